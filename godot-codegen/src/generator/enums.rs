@@ -357,7 +357,8 @@ fn make_enum_str_functions(enum_: &Enum) -> TokenStream {
 
 /// Creates implementations for bitwise operators for the given enum.
 ///
-/// Currently, this is just [`BitOr`](std::ops::BitOr) for bitfields but that could be expanded in the future.
+/// For bitfields, this is [`BitOr`](std::ops::BitOr) and [`BitAnd`](std::ops::BitAnd), plus their assigning versions. Enums with an
+/// accompanying mask only support [`BitOr`](std::ops::BitOr) with that mask.
 fn make_enum_bitwise_operators(enum_: &Enum) -> TokenStream {
     let name = &enum_.name;
 
@@ -372,6 +373,29 @@ fn make_enum_bitwise_operators(enum_: &Enum) -> TokenStream {
                     Self { ord: self.ord | rhs.ord }
                 }
             }
+
+            impl std::ops::BitOrAssign for #name {
+                #[inline]
+                fn bitor_assign(&mut self, rhs: Self) {
+                    *self = *self | rhs;
+                }
+            }
+
+            impl std::ops::BitAnd for #name {
+                type Output = Self;
+
+                #[inline]
+                fn bitand(self, rhs: Self) -> Self::Output {
+                    Self { ord: self.ord & rhs.ord }
+                }
+            }
+
+            impl std::ops::BitAndAssign for #name {
+                #[inline]
+                fn bitand_assign(&mut self, rhs: Self) {
+                    *self = *self & rhs;
+                }
+            }
         }
     } else if let Some(mask_enum) = special_cases::as_enum_bitmaskable(enum_) {
         // Enum that has an accompanying bitfield for masking.
@@ -220,50 +220,101 @@ impl Aabb {
     }
 
     /// Returns the normalized longest axis of the AABB.
+    ///
+    /// If the longest size is shared by multiple axes, the first of them is chosen; see [`longest_axis_index()`][Self::longest_axis_index].
+    ///
+    /// _Godot equivalent: `AABB.get_longest_axis()`_
     #[inline]
-    pub fn longest_axis(self) -> Option<Vector3> {
-        self.longest_axis_index().map(|axis| match axis {
-            Vector3Axis::X => Vector3::RIGHT,
-            Vector3Axis::Y => Vector3::UP,
-            Vector3Axis::Z => Vector3::BACK,
-        })
+    #[doc(alias = "get_longest_axis")]
+    pub fn longest_axis(self) -> Vector3 {
+        Self::axis_direction(self.longest_axis_index())
     }
 
     /// Returns the index of the longest axis of the AABB (according to Vector3's AXIS_* constants).
+    ///
+    /// If the longest size is shared by multiple axes (e.g. for cubes and zero-size boxes), this returns the lowest of those axes, in
+    /// order X, Y, Z -- same as Godot.
+    ///
+    /// _Godot equivalent: `AABB.get_longest_axis_index()`_
     #[inline]
-    pub fn longest_axis_index(self) -> Option<Vector3Axis> {
-        self.size.max_axis()
+    #[doc(alias = "get_longest_axis_index")]
+    pub fn longest_axis_index(self) -> Vector3Axis {
+        let size = self.size;
+
+        let mut axis = Vector3Axis::X;
+        let mut max_size = size.x;
+        if size.y > max_size {
+            axis = Vector3Axis::Y;
+            max_size = size.y;
+        }
+        if size.z > max_size {
+            axis = Vector3Axis::Z;
+        }
+
+        axis
     }
 
     /// Returns the scalar length of the longest axis of the AABB.
+    ///
+    /// _Godot equivalent: `AABB.get_longest_axis_size()`_
     #[inline]
+    #[doc(alias = "get_longest_axis_size")]
     pub fn longest_axis_size(self) -> real {
         let size = self.size;
         size.x.max(size.y).max(size.z)
     }
 
     /// Returns the normalized shortest axis of the AABB.
+    ///
+    /// If the shortest size is shared by multiple axes, the first of them is chosen; see [`shortest_axis_index()`][Self::shortest_axis_index].
+    ///
+    /// _Godot equivalent: `AABB.get_shortest_axis()`_
     #[inline]
-    pub fn shortest_axis(self) -> Option<Vector3> {
-        self.shortest_axis_index().map(|axis| match axis {
-            Vector3Axis::X => Vector3::RIGHT,
-            Vector3Axis::Y => Vector3::UP,
-            Vector3Axis::Z => Vector3::BACK,
-        })
+    #[doc(alias = "get_shortest_axis")]
+    pub fn shortest_axis(self) -> Vector3 {
+        Self::axis_direction(self.shortest_axis_index())
     }
 
-    /// Returns the index of the shortest axis of the AABB (according to Vector3::AXIS* enum).
+    /// Returns the index of the shortest axis of the AABB (according to Vector3's AXIS_* constants).
+    ///
+    /// If the shortest size is shared by multiple axes, this returns the lowest of those axes, in order X, Y, Z -- same as Godot.
+    ///
+    /// _Godot equivalent: `AABB.get_shortest_axis_index()`_
     #[inline]
-    pub fn shortest_axis_index(self) -> Option<Vector3Axis> {
-        self.size.min_axis()
+    #[doc(alias = "get_shortest_axis_index")]
+    pub fn shortest_axis_index(self) -> Vector3Axis {
+        let size = self.size;
+
+        let mut axis = Vector3Axis::X;
+        let mut min_size = size.x;
+        if size.y < min_size {
+            axis = Vector3Axis::Y;
+            min_size = size.y;
+        }
+        if size.z < min_size {
+            axis = Vector3Axis::Z;
+        }
+
+        axis
     }
 
     /// Returns the scalar length of the shortest axis of the AABB.
+    ///
+    /// _Godot equivalent: `AABB.get_shortest_axis_size()`_
     #[inline]
+    #[doc(alias = "get_shortest_axis_size")]
     pub fn shortest_axis_size(self) -> real {
         self.size.x.min(self.size.y.min(self.size.z))
     }
 
+    fn axis_direction(axis: Vector3Axis) -> Vector3 {
+        match axis {
+            Vector3Axis::X => Vector3::RIGHT,
+            Vector3Axis::Y => Vector3::UP,
+            Vector3Axis::Z => Vector3::BACK,
+        }
+    }
+
     /// Returns the support point in a given direction. This is useful for collision detection algorithms.
     #[inline]
     #[doc(alias = "get_support")]
@@ -296,6 +347,7 @@ impl Aabb {
             && self.position.y <= end_b.y
             && end.y >= b.position.y
             && self.position.z <= end_b.z
+            && end.z >= b.position.z
     }
 
     /// Checks whether two AABBs have at least one _inner_ point in common (not on the borders).
@@ -503,12 +555,51 @@ mod test {
             size: Vector3::new(4.0, 6.0, 8.0),
         };
 
-        assert_eq!(aabb.shortest_axis(), Some(Vector3::RIGHT));
-        assert_eq!(aabb.longest_axis(), Some(Vector3::BACK));
+        assert_eq!(aabb.shortest_axis(), Vector3::RIGHT);
+        assert_eq!(aabb.longest_axis(), Vector3::BACK);
         assert_eq!(aabb.shortest_axis_size(), 4.0);
         assert_eq!(aabb.longest_axis_size(), 8.0);
-        assert_eq!(aabb.shortest_axis_index(), Some(Vector3Axis::X));
-        assert_eq!(aabb.longest_axis_index(), Some(Vector3Axis::Z));
+        assert_eq!(aabb.shortest_axis_index(), Vector3Axis::X);
+        assert_eq!(aabb.longest_axis_index(), Vector3Axis::Z);
+    }
+
+    #[test]
+    fn test_axes_non_cubic() {
+        let aabb = Aabb::new(Vector3::new(-1.0, 2.0, 3.0), Vector3::new(2.0, 5.0, 3.0));
+
+        assert_eq!(aabb.longest_axis_index(), Vector3Axis::Y);
+        assert_eq!(aabb.longest_axis(), Vector3::UP);
+        assert_eq!(aabb.longest_axis_size(), 5.0);
+        assert_eq!(aabb.shortest_axis_index(), Vector3Axis::X);
+        assert_eq!(aabb.shortest_axis_size(), 2.0);
+        assert_eq!(aabb.center(), Vector3::new(0.0, 4.5, 4.5));
+        assert_eq!(aabb.volume(), 30.0);
+
+        // Ties pick the lowest axis, like Godot.
+        let tie = Aabb::new(Vector3::ZERO, Vector3::new(4.0, 1.0, 4.0));
+        assert_eq!(tie.longest_axis_index(), Vector3Axis::X);
+        assert_eq!(tie.longest_axis(), Vector3::RIGHT);
+        assert_eq!(tie.longest_axis_size(), 4.0);
+        assert_eq!(tie.shortest_axis_index(), Vector3Axis::Y);
+
+        let tie = Aabb::new(Vector3::ZERO, Vector3::new(3.0, 2.0, 2.0));
+        assert_eq!(tie.shortest_axis_index(), Vector3Axis::Y);
+        assert_eq!(tie.shortest_axis(), Vector3::UP);
+        assert_eq!(tie.longest_axis_index(), Vector3Axis::X);
+
+        let cube = Aabb::new(Vector3::ZERO, Vector3::new(2.0, 2.0, 2.0));
+        assert_eq!(cube.longest_axis_index(), Vector3Axis::X);
+        assert_eq!(cube.shortest_axis_index(), Vector3Axis::X);
+
+        // Zero-size box.
+        let point = Aabb::new(Vector3::new(1.0, 2.0, 3.0), Vector3::ZERO);
+        assert_eq!(point.longest_axis_index(), Vector3Axis::X);
+        assert_eq!(point.shortest_axis_index(), Vector3Axis::X);
+        assert_eq!(point.longest_axis_size(), 0.0);
+        assert_eq!(point.center(), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(point.volume(), 0.0);
+        assert!(!point.has_volume());
+        assert!(!point.has_surface());
     }
 
     #[test]
@@ -557,6 +648,80 @@ mod test {
         assert!(aabb1.intersects(aabb1));
     }
 
+    #[test]
+    fn test_intersects_touching_and_disjoint() {
+        let unit = Aabb::new(Vector3::ZERO, Vector3::ONE);
+
+        // Touching faces along each axis.
+        for offset in [Vector3::RIGHT, Vector3::UP, Vector3::BACK] {
+            let touching = Aabb::new(offset, Vector3::ONE);
+            assert!(unit.intersects(touching), "touching at {offset}");
+            assert!(touching.intersects(unit), "touching at {offset}");
+            assert!(
+                !unit.intersects_exclude_borders(touching),
+                "touching at {offset}"
+            );
+
+            let disjoint = Aabb::new(offset * 2.0, Vector3::ONE);
+            assert!(!unit.intersects(disjoint), "disjoint at {offset}");
+            assert!(!disjoint.intersects(unit), "disjoint at {offset}");
+            assert_eq!(unit.intersect(disjoint), None, "disjoint at {offset}");
+        }
+
+        // Touching only at a corner.
+        let corner = Aabb::new(Vector3::ONE, Vector3::ONE);
+        assert!(unit.intersects(corner));
+        assert_eq!(
+            unit.intersect(corner),
+            Some(Aabb::new(Vector3::ONE, Vector3::ZERO))
+        );
+
+        // Zero-size AABB (a single point) inside a box.
+        let point = Aabb::new(Vector3::splat(0.5), Vector3::ZERO);
+        assert!(unit.intersects(point));
+        assert!(!unit.intersects_exclude_borders(point));
+        assert_eq!(unit.intersect(point), Some(point));
+    }
+
+    #[test]
+    fn test_merge() {
+        let a = Aabb::new(Vector3::ZERO, Vector3::ONE);
+        let b = Aabb::new(Vector3::new(3.0, -2.0, 0.5), Vector3::new(1.0, 1.0, 0.25));
+
+        let merged = Aabb::new(Vector3::new(0.0, -2.0, 0.0), Vector3::new(4.0, 3.0, 1.0));
+        assert_eq!(a.merge(b), merged);
+        assert_eq!(b.merge(a), merged);
+        assert_eq!(a.merge(a), a);
+
+        // Merging with a zero-size AABB extends to that point.
+        let point = Aabb::new(Vector3::splat(2.0), Vector3::ZERO);
+        assert_eq!(
+            a.merge(point),
+            Aabb::new(Vector3::ZERO, Vector3::splat(2.0))
+        );
+        assert_eq!(a.merge(point), a.expand(Vector3::splat(2.0)));
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let aabb = Aabb::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(2.0, 2.0, 2.0));
+
+        assert!(aabb.contains_point(Vector3::new(2.0, 3.0, 4.0)));
+        assert!(!aabb.contains_point(Vector3::new(0.0, 3.0, 4.0)));
+        assert!(!aabb.contains_point(Vector3::new(2.0, 3.0, 10.0)));
+
+        // Faces at `position` are included, faces at `end` are not.
+        assert!(aabb.contains_point(Vector3::new(1.0, 2.0, 3.0)));
+        assert!(aabb.contains_point(Vector3::new(1.0, 3.0, 4.0)));
+        assert!(!aabb.contains_point(Vector3::new(3.0, 3.0, 4.0)));
+        assert!(!aabb.contains_point(Vector3::new(2.0, 4.0, 4.0)));
+        assert!(!aabb.contains_point(Vector3::new(2.0, 3.0, 5.0)));
+
+        // A zero-size AABB contains no points.
+        let empty = Aabb::new(Vector3::ONE, Vector3::ZERO);
+        assert!(!empty.contains_point(Vector3::ONE));
+    }
+
     #[test]
     fn test_intersection() {
         // Create AABBs for testing
@@ -162,10 +162,18 @@ impl Basis {
         }
     }
 
+    /// Creates an orthonormal basis whose forward axis points in the direction `target`, with `up` determining the roll.
+    ///
     /// If `use_model_front` is true, the +Z axis (asset front) is treated as forward (implies +X is left)
     /// and points toward the target position. By default, the -Z axis (camera forward) is treated as forward
     /// (implies +X is right).
     ///
+    /// `target` is a direction, not a position; see [`Transform3D::looking_at()`](crate::builtin::Transform3D::looking_at) to orient
+    /// towards a point. `up` does not need to be perpendicular to `target`, but must not be parallel to it. If `target` or `up` is zero,
+    /// or both are parallel, Godot debug builds print an error and return [`Basis::IDENTITY`]. Release builds skip these checks and normalize
+    /// zero vectors to zero, so the result stays finite but is degenerate: a zero `target` yields an all-zero basis, and an `up` parallel
+    /// to `target` yields zero X and Y columns; only the Z column is set, to the normalized `target` (negated unless `use_model_front`).
+    ///
     /// _Godot equivalent: `Basis.looking_at()`_
     pub fn looking_at(target: Vector3, up: Vector3, use_model_front: bool) -> Self {
         super::inner::InnerBasis::looking_at(target, up, use_model_front)
@@ -200,10 +208,18 @@ impl Basis {
 
     /// Creates a [`Quaternion`] representing the same rotation as this basis.
     ///
+    /// Scale is removed by orthonormalizing the basis first. If the basis contains a reflection (negative determinant), the reflection
+    /// is removed as well, so the result is always a proper rotation.
+    ///
     /// _Godot equivalent: `Basis.get_rotation_quaternion()`_
     #[doc(alias = "get_rotation_quaternion")]
     pub fn get_quaternion(&self) -> Quaternion {
-        RQuat::from_mat3(&self.orthonormalized().to_glam()).to_front()
+        let mut rotation = self.orthonormalized();
+        if rotation.determinant() < 0.0 {
+            rotation = rotation.scaled(Vector3::splat(-1.0));
+        }
+
+        RQuat::from_mat3(&rotation.to_glam()).to_front()
     }
 
     #[deprecated = "Renamed to `get_quaternion()`"]
@@ -242,6 +258,10 @@ impl Basis {
     ///
     /// The order of the angles are given by `order`. To use the default order `YXZ`, see [`get_euler()`](Self::get_euler).
     ///
+    /// The basis is expected to be a pure rotation; for scaled or skewed bases, call [`orthonormalized()`](Self::orthonormalized) first.
+    /// In gimbal lock (the middle angle is ±90°), the first and last axes coincide. The combined rotation around them is then stored
+    /// in one of the two angles, while the other one is 0.
+    ///
     /// _Godot equivalent: `Basis.get_euler()`_
     pub fn get_euler_with(&self, order: EulerOrder) -> Vector3 {
         use glam::swizzles::Vec3Swizzles as _;
@@ -381,6 +401,9 @@ impl Basis {
 
     /// Introduce an additional scaling specified by the given 3D scaling factor.
     ///
+    /// The scale is applied in global space, i.e. the rows of the basis are multiplied. A zero component results in a singular basis
+    /// (determinant 0), which cannot be inverted.
+    ///
     /// _Godot equivalent: `Basis.scaled()`_
     #[must_use]
     pub fn scaled(&self, scale: Vector3) -> Self {
@@ -436,9 +459,18 @@ impl Basis {
     /// Introduce an additional rotation around the given `axis` by `angle`
     /// (in radians). The axis must be a normalized vector.
     ///
+    /// The rotation is applied in global space, i.e. multiplied from the left.
+    ///
+    /// # Panics
+    /// If `axis` is not normalized. Godot reports an error in this case.
+    ///
     /// _Godot equivalent: `Basis.rotated()`_
     #[must_use]
     pub fn rotated(&self, axis: Vector3, angle: real) -> Self {
+        assert!(
+            axis.is_normalized(),
+            "Basis::rotated(): axis {axis:?} is not normalized"
+        );
         Self::from_axis_angle(axis, angle) * (*self)
     }
 
@@ -827,6 +859,64 @@ mod test {
         }
     }
 
+    #[test]
+    fn orthonormalized_removes_scale_and_skew() {
+        let basis = Basis::from_cols(
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(1.0, 3.0, 0.0),
+            Vector3::new(0.5, 0.5, 0.25),
+        );
+        let ortho = basis.orthonormalized();
+
+        assert_eq_approx!(ortho.transposed() * ortho, Basis::IDENTITY);
+        assert_eq_approx!(ortho.determinant(), 1.0);
+
+        // Gram-Schmidt keeps the direction of the first column.
+        assert_eq_approx!(ortho.col_a(), Vector3::RIGHT);
+        assert_eq_approx!(ortho.col_b(), Vector3::UP);
+    }
+
+    #[test]
+    fn get_quaternion_of_scaled_basis() {
+        let rotation = Basis::from_axis_angle(Vector3::new(1.0, 2.0, -1.0).normalized(), 0.7);
+        let expected = rotation.get_quaternion();
+
+        let scaled = rotation * Basis::from_scale(Vector3::new(2.0, 0.5, 3.0));
+        assert_eq_approx!(Basis::from_quaternion(scaled.get_quaternion()), rotation);
+
+        // Reflections are removed, too.
+        let mirrored = rotation * Basis::from_scale(Vector3::splat(-2.0));
+        assert_eq_approx!(mirrored.get_quaternion(), expected);
+    }
+
+    #[test]
+    fn get_euler_known_rotations() {
+        let angles = Vector3::new(0.1, 0.2, 0.3);
+        for order in [
+            EulerOrder::XYZ,
+            EulerOrder::XZY,
+            EulerOrder::YXZ,
+            EulerOrder::YZX,
+            EulerOrder::ZXY,
+            EulerOrder::ZYX,
+        ] {
+            let basis = Basis::from_euler(order, angles);
+            assert_eq_approx!(basis.get_euler_with(order), angles, "order {order:?}");
+        }
+
+        assert_eq_approx!(
+            Basis::from_axis_angle(Vector3::UP, FRAC_PI_2).get_euler(),
+            Vector3::new(0.0, FRAC_PI_2, 0.0)
+        );
+
+        // Gimbal lock for YXZ: pitch of 90° makes yaw and roll indistinguishable.
+        let locked = Basis::from_euler(EulerOrder::YXZ, Vector3::new(FRAC_PI_2, 0.4, 0.0));
+        let euler = locked.get_euler();
+        assert_eq_approx!(euler.x, FRAC_PI_2);
+        assert_eq_approx!(euler.z, 0.0);
+        assert_eq_approx!(Basis::from_euler(EulerOrder::YXZ, euler), locked);
+    }
+
     // Translated from Godot
     #[test]
     fn basis_finite_number_test() {
@@ -870,6 +960,61 @@ mod test {
         );
     }
 
+    #[test]
+    fn rotated_scaled_transposed() {
+        let rotation = Basis::IDENTITY.rotated(Vector3::UP, FRAC_PI_2);
+        let expected_rotation = Basis::from_rows(
+            Vector3::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(-1.0, 0.0, 0.0),
+        );
+        assert_eq_approx!(rotation, expected_rotation);
+        assert_eq_approx!(rotation * Vector3::RIGHT, Vector3::FORWARD);
+
+        // Scaling after rotating scales the rows; scaling before rotating scales the columns.
+        let scale = Vector3::new(2.0, 3.0, 4.0);
+        assert_eq_approx!(
+            rotation.scaled(scale),
+            Basis::from_rows(
+                Vector3::new(0.0, 0.0, 2.0),
+                Vector3::new(0.0, 3.0, 0.0),
+                Vector3::new(-4.0, 0.0, 0.0),
+            )
+        );
+        assert_eq_approx!(
+            Basis::IDENTITY
+                .scaled(scale)
+                .rotated(Vector3::UP, FRAC_PI_2),
+            Basis::from_rows(
+                Vector3::new(0.0, 0.0, 4.0),
+                Vector3::new(0.0, 3.0, 0.0),
+                Vector3::new(-2.0, 0.0, 0.0),
+            )
+        );
+
+        // Transposing a rotation inverts it.
+        assert_eq_approx!(rotation.transposed(), rotation.inverse());
+        assert_eq_approx!(
+            rotation.scaled(scale).transposed(),
+            Basis::from_rows(
+                Vector3::new(0.0, 0.0, -4.0),
+                Vector3::new(0.0, 3.0, 0.0),
+                Vector3::new(2.0, 0.0, 0.0),
+            )
+        );
+
+        // Zero scale collapses an axis.
+        let flat = rotation.scaled(Vector3::new(1.0, 0.0, 1.0));
+        assert_eq!(flat.determinant(), 0.0);
+        assert_eq_approx!(flat * Vector3::UP, Vector3::ZERO);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotated_non_normalized_axis() {
+        let _ = Basis::IDENTITY.rotated(Vector3::new(0.0, 2.0, 0.0), 1.0);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
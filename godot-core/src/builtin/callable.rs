@@ -9,6 +9,7 @@ use godot_ffi as sys;
 
 use crate::builtin::{inner, GString, StringName, Variant, VariantArray};
 use crate::classes;
+use crate::meta::error::CallError;
 use crate::meta::{GodotType, ToGodot};
 use crate::obj::bounds::DynMemory;
 use crate::obj::Bounds;
@@ -312,8 +313,25 @@ impl Callable {
         self.as_inner().callv(arguments)
     }
 
+    /// Calls the method represented by this callable, returning `Err` on failure.
+    ///
+    /// Unlike [`call()`][Self::call] and [`callv()`][Self::callv], errors reported by Godot are not just printed, but returned as a
+    /// [`CallError`]. Its `Display` impl describes the reason, e.g. a wrong number of arguments, an argument that cannot be converted to
+    /// the parameter type, or an invalid method. If the callable is a Rust function that failed, the original error is available
+    /// through `Error::source()`.
+    ///
+    /// There is no fallible counterpart to `call_deferred()`, since the call happens after this method has returned.
+    ///
+    /// _Godot equivalent: `call`_
+    pub fn try_call(&self, arguments: &[Variant]) -> Result<Variant, CallError> {
+        self.to_variant().try_call("call", arguments)
+    }
+
     /// Returns a copy of this Callable with one or more arguments bound, reading them from an array.
     ///
+    /// When the returned callable is invoked, the bound arguments are passed _after_ the call arguments. For example, binding `[2, 3]`
+    /// and calling with `[1]` invokes the method with `(1, 2, 3)`. To bind from a slice instead, use `bind()`.
+    ///
     /// _Godot equivalent: `bindv`_
     pub fn bindv(&self, arguments: &VariantArray) -> Self {
         self.as_inner().bindv(arguments)
@@ -326,6 +344,9 @@ impl Callable {
     ///
     /// Getting the name of a lambda errors instead of returning its name, see [godot#73052].
     ///
+    /// The same applies to other custom callables, such as those created with [`from_local_fn()`][Self::from_local_fn]: Godot prints an
+    /// error and `None` is returned, even though a name was passed on construction. Use [`is_custom()`][Self::is_custom] to check beforehand.
+    ///
     /// _Godot equivalent: `get_method`_
     ///
     /// [godot#73052]: https://github.com/godotengine/godot/issues/73052
@@ -345,6 +366,7 @@ impl Callable {
     /// target or not). Also returns `None` if the object is dead. You can differentiate these two cases using [`object_id()`][Self::object_id].
     ///
     /// _Godot equivalent: `get_object`_
+    #[doc(alias = "get_object")]
     pub fn object(&self) -> Option<Gd<classes::Object>> {
         // Increment refcount because we're getting a reference, and `InnerCallable::get_object` doesn't
         // increment the refcount.
@@ -361,6 +383,7 @@ impl Callable {
     /// If the pointed-to object is dead, the ID will still be returned. Use [`object()`][Self::object] to check for liveness.
     ///
     /// _Godot equivalent: `get_object_id`_
+    #[doc(alias = "get_object_id")]
     pub fn object_id(&self) -> Option<InstanceId> {
         let id = self.as_inner().get_object_id();
         InstanceId::try_from_i64(id)
@@ -10,7 +10,7 @@ use std::{cmp, fmt};
 
 use crate::builtin::*;
 use crate::meta;
-use crate::meta::error::{ConvertError, FromGodotError, FromVariantError};
+use crate::meta::error::{ConvertError, ErrorKind, FromGodotError, FromVariantError};
 use crate::meta::{
     element_godot_type_name, element_variant_type, ArrayElement, ArrayTypeInfo, AsArg, ClassName,
     CowArg, FromGodot, GodotConvert, GodotFfiVariant, GodotType, ParamType, PropertyHintInfo,
@@ -999,6 +999,40 @@ fn correct_variant_t() {
 }
 
 impl VariantArray {
+    /// Returns an iterator that converts each element of this untyped array to `U`.
+    ///
+    /// Elements that cannot be converted are yielded as `Err`, without ending the iteration. To stop at the first such element, collect
+    /// into a `Result<Vec<U>, ConvertError>`. If all elements are known to have the same type, consider using a typed [`Array<U>`] instead.
+    ///
+    /// Like with [`iter_shared()`][Self::iter_shared], modifying the array through another reference during iteration is safe, but
+    /// causes the iterator to behave in an unspecified way (it does not operate on a snapshot).
+    pub fn iter_typed<U: FromGodot>(&self) -> impl Iterator<Item = Result<U, ConvertError>> + '_ {
+        self.iter_shared().map(|variant| variant.try_to::<U>())
+    }
+
+    /// Converts each element of this untyped array to `U`, collecting them into a `Vec`.
+    ///
+    /// Fails on the first element that cannot be converted; the error message contains its index. For typed arrays, the infallible
+    /// `Vec::from(&array)` can be used instead.
+    ///
+    /// A `TryFrom<&VariantArray>` impl is not possible here, as it would overlap with the `From<&Array<T>> for Vec<T>` impl for
+    /// `T = Variant`.
+    pub fn try_to_vec<U: FromGodot>(&self) -> Result<Vec<U>, ConvertError> {
+        self.iter_shared()
+            .enumerate()
+            .map(|(index, element)| {
+                element.try_to::<U>().map_err(|err| {
+                    let message =
+                        format!("Array element at index {index} cannot be converted: {err}");
+                    ConvertError::with_kind_value(
+                        ErrorKind::Custom(Some(message.into())),
+                        self.clone(),
+                    )
+                })
+            })
+            .collect()
+    }
+
     /// # Safety
     /// - Variant must have type `VariantType::ARRAY`.
     /// - Subsequent operations on this array must not rely on the type of the array.
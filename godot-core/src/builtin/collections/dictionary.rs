@@ -8,6 +8,7 @@
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Variant, VariantArray};
+use crate::meta::error::ConvertError;
 use crate::meta::{FromGodot, ToGodot};
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -125,6 +126,28 @@ impl Dictionary {
         }
     }
 
+    /// Returns the value for the given key converted to `V`, or `None` if absent or not convertible.
+    ///
+    /// This does not let you differentiate absent keys from values of the wrong type. If you need that, use
+    /// [`get_typed_result()`][Self::get_typed_result] instead.
+    ///
+    /// Writing needs no typed counterpart, as [`set()`][Self::set] and [`insert()`][Self::insert] already accept any `ToGodot` value.
+    pub fn get_typed<K: ToGodot, V: FromGodot>(&self, key: K) -> Option<V> {
+        self.get_typed_result(key).ok().flatten()
+    }
+
+    /// Returns the value for the given key converted to `V`.
+    ///
+    /// - `Ok(Some(value))` if the key is present and its value can be converted.
+    /// - `Ok(None)` if the key is absent.
+    /// - `Err(error)` if the key is present, but its value cannot be converted to `V`.
+    pub fn get_typed_result<K: ToGodot, V: FromGodot>(
+        &self,
+        key: K,
+    ) -> Result<Option<V>, ConvertError> {
+        self.get(key).map(|value| value.try_to::<V>()).transpose()
+    }
+
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
     ///
     /// This method does not let you differentiate `NIL` values stored as values from absent keys.
@@ -148,6 +171,8 @@ impl Dictionary {
 
     /// Returns `true` if the dictionary contains all the given keys.
     ///
+    /// Useful to validate that a dictionary follows a schema. Returns `true` for an empty `keys` array; duplicates in `keys` are allowed.
+    ///
     /// _Godot equivalent: `has_all`_
     #[doc(alias = "has_all")]
     pub fn contains_all_keys(&self, keys: &VariantArray) -> bool {
@@ -171,19 +196,31 @@ impl Dictionary {
     ///
     /// Unlike Godot, this will return `None` if the key does not exist and `Some(Variant::nil())` the key is `NIL`.
     ///
+    /// If multiple keys map to `value`, the first one in insertion order is returned. Values are compared like in Godot, which
+    /// requires equal types (e.g. `1` does not match `1.0`).
+    ///
     /// This operation is rarely needed and very inefficient. If you find yourself needing it a lot, consider
     /// using a `HashMap` or `Dictionary` with the inverse mapping (`V` -> `K`).
     ///
     /// _Godot equivalent: `find_key`_
     #[doc(alias = "find_key")]
     pub fn find_key_by_value<V: ToGodot>(&self, value: V) -> Option<Variant> {
-        let key = self.as_inner().find_key(&value.to_variant());
+        let value = value.to_variant();
+        let key = self.as_inner().find_key(&value);
 
-        if !key.is_nil() || self.contains_key(key.clone()) {
-            Some(key)
-        } else {
-            None
+        // Godot returns NIL both if no key is found and if the NIL key matches. Checking only for presence of a NIL key is not enough,
+        // since its value may be different.
+        if !key.is_nil() {
+            return Some(key);
         }
+
+        let nil_value = self.get(Variant::nil())?;
+
+        // SAFETY: both are valid variants. Same (type-strict) comparison as Variant::operator== used by Godot's find_key().
+        let matches =
+            unsafe { interface_fn!(variant_hash_compare)(nil_value.var_sys(), value.var_sys()) };
+
+        (matches != 0).then_some(key)
     }
 
     /// Removes all key-value pairs from the dictionary.
@@ -236,6 +273,8 @@ impl Dictionary {
 
     /// Creates a new `Array` containing all the keys currently in the dictionary.
     ///
+    /// Keys are in insertion order, the same order as in [`iter_shared()`][Self::iter_shared].
+    ///
     /// _Godot equivalent: `keys`_
     #[doc(alias = "keys")]
     pub fn keys_array(&self) -> VariantArray {
@@ -244,6 +283,8 @@ impl Dictionary {
 
     /// Creates a new `Array` containing all the values currently in the dictionary.
     ///
+    /// Values are in the insertion order of their keys, matching [`keys_array()`][Self::keys_array].
+    ///
     /// _Godot equivalent: `values`_
     #[doc(alias = "values")]
     pub fn values_array(&self) -> VariantArray {
@@ -252,7 +293,10 @@ impl Dictionary {
 
     /// Copies all keys and values from `other` into `self`.
     ///
-    /// If `overwrite` is true, it will overwrite pre-existing keys.
+    /// If `overwrite` is true, it will overwrite pre-existing keys. Otherwise, values of keys already present in `self` are kept.
+    ///
+    /// The merge is shallow: nested dictionaries and arrays are not merged recursively, but replaced (or kept) as a whole, and
+    /// shared with `other` afterward. Merging a dictionary with itself (or a clone referring to the same storage) has no effect.
     ///
     /// _Godot equivalent: `merge`_
     #[doc(alias = "merge")]
@@ -291,6 +335,9 @@ impl Dictionary {
     /// The pairs are each of type `(Variant, Variant)`. Each pair references the original `Dictionary`, but instead of a `&`-reference
     /// to key-value pairs as you might expect, the iterator returns a (cheap, shallow) copy of each key-value pair.
     ///
+    /// Godot dictionaries preserve insertion order, so pairs are yielded in the order their keys were first inserted. Overwriting the
+    /// value of an existing key keeps its position; removing a key and inserting it again moves it to the end.
+    ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating over it. This will not result in
     /// unsoundness or crashes, but will cause the iterator to behave in an unspecified way.
     ///
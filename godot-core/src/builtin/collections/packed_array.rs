@@ -13,6 +13,7 @@ use godot_ffi as sys;
 
 use crate::builtin::collections::extend_buffer::ExtendBuffer;
 use crate::builtin::*;
+use crate::meta::error::{ConvertError, ErrorKind};
 use crate::meta::{AsArg, ToGodot};
 use std::mem::size_of;
 use std::{fmt, ops, ptr};
@@ -251,7 +252,8 @@ macro_rules! impl_packed_array {
 
             /// Returns an exclusive Rust slice of the array.
             ///
-            /// The resulting slice can be further subdivided or converted into raw pointers.
+            /// The resulting slice can be further subdivided or converted into raw pointers. It points directly into Godot's storage,
+            /// so writes through it are visible to Godot without any copying back.
             ///
             /// See also [`as_slice`][Self::as_slice] to get shared slices, and
             /// [`subarray`][Self::subarray] to get a sub-array as a copy.
@@ -432,6 +434,8 @@ macro_rules! impl_packed_array {
         }
 
         #[doc = concat!("Creates a `", stringify!($PackedArray), "` from the given slice.")]
+        ///
+        /// Allocates the array once with the final size and copies all elements in one go, without per-element FFI calls.
         impl From<&[$Element]> for $PackedArray {
             fn from(slice: &[$Element]) -> Self {
                 if slice.is_empty() {
@@ -446,6 +450,15 @@ macro_rules! impl_packed_array {
             }
         }
 
+        #[doc = concat!("Borrows the contents of a `", stringify!($PackedArray), "` as a slice, like [`as_slice()`][", stringify!($PackedArray), "::as_slice].")]
+        ///
+        /// Allows passing packed arrays to generic APIs accepting `impl AsRef<[T]>`, without copying.
+        impl AsRef<[$Element]> for $PackedArray {
+            fn as_ref(&self) -> &[$Element] {
+                self.as_slice()
+            }
+        }
+
         #[doc = concat!("Creates a `", stringify!($PackedArray), "` from the given Rust array.")]
         impl<const N: usize> From<[$Element; N]> for $PackedArray {
             fn from(arr: [$Element; N]) -> Self {
@@ -884,6 +897,16 @@ impl_packed_array!(
     },
 );
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Element layout
+
+// as_slice() and as_mut_slice() reinterpret Godot's storage as Rust elements without copying, so the vector and color types must have
+// the same layout as their C++ counterparts: `real_t` components for vectors, `float` components for colors, no padding.
+sys::static_assert_eq_size_align!(Vector2, [real; 2]);
+sys::static_assert_eq_size_align!(Vector3, [real; 3]);
+sys::static_assert_eq_size_align!(Vector4, [real; 4]);
+sys::static_assert_eq_size_align!(Color, [f32; 4]);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Abstractions that may later simplify the migration to generics.
 
@@ -945,7 +968,8 @@ macro_rules! declare_encode_decode {
         pub fn $encode_fn(&mut self, byte_offset: usize, value: $Ty) -> Result<(), ()> {
             // sys::static_assert!(std::mem::size_of::<$Ty>() == $bytes); -- used for testing, can't keep enabled due to half-floats.
 
-            if byte_offset + $bytes > self.len() {
+            // Written to not overflow for huge offsets.
+            if byte_offset > self.len() || self.len() - byte_offset < $bytes {
                 return Err(());
             }
 
@@ -963,7 +987,8 @@ macro_rules! declare_encode_decode {
         /// [`as_slice()`][Self::as_slice] and the various Rust standard APIs such as
         #[doc = concat!("[`", stringify!($Ty), "::from_be_bytes()`].")]
         pub fn $decode_fn(&self, byte_offset: usize) -> Result<$Ty, ()> {
-            if byte_offset + $bytes > self.len() {
+            // Written to not overflow for huge offsets.
+            if byte_offset > self.len() || self.len() - byte_offset < $bytes {
                 return Err(());
             }
 
@@ -1098,6 +1123,81 @@ impl PackedByteArray {
         (variant, decoded_size)
     }
 
+    /// Creates a byte array storing each boolean as one byte, `1` for `true` and `0` for `false`.
+    ///
+    /// Godot has no packed array for booleans; this provides a compact representation that can be passed to Godot APIs.
+    /// Use [`to_bools()`][Self::to_bools] to convert back.
+    pub fn from_bools(bools: &[bool]) -> Self {
+        bools.iter().map(|&b| u8::from(b)).collect()
+    }
+
+    /// Interprets each byte as a boolean, where any nonzero value is `true`.
+    ///
+    /// The resulting vector has the same length as this array. See also [`from_bools()`][Self::from_bools].
+    pub fn to_bools(&self) -> Vec<bool> {
+        self.as_slice().iter().map(|&byte| byte != 0).collect()
+    }
+
+    /// Creates a byte array from a slice of plain-old-data values, copying their in-memory representation.
+    ///
+    /// This is useful for binary formats such as network packets. The bytes use the native endianness and include any padding of `T`,
+    /// which `bytemuck::Pod` guarantees to be absent for well-formed types. Use [`to_pod_vec()`][Self::to_pod_vec] to convert back.
+    ///
+    /// # Panics
+    /// If `T` is a zero-sized type.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(published_docs, doc(cfg(feature = "bytemuck")))]
+    pub fn from_pod_slice<T: bytemuck::Pod>(values: &[T]) -> Self {
+        let bytes: &[u8] = bytemuck::cast_slice(values);
+        Self::from(bytes)
+    }
+
+    /// Reinterprets the bytes of this array as a vector of plain-old-data values.
+    ///
+    /// Returns `Err` if the length of this array is not a multiple of `size_of::<T>()`, or if `T` is zero-sized. The bytes are copied,
+    /// so there are no alignment requirements on the array's storage.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(published_docs, doc(cfg(feature = "bytemuck")))]
+    pub fn to_pod_vec<T: bytemuck::Pod>(&self) -> Result<Vec<T>, ConvertError> {
+        let element_size = size_of::<T>();
+        let bytes = self.as_slice();
+        if element_size == 0 || bytes.len() % element_size != 0 {
+            let message = format!(
+                "PackedByteArray of length {} is not a multiple of element size {element_size}",
+                bytes.len()
+            );
+            return Err(ConvertError::with_kind_value(
+                ErrorKind::Custom(Some(message.into())),
+                self.clone(),
+            ));
+        }
+
+        Ok(bytes
+            .chunks_exact(element_size)
+            .map(bytemuck::pod_read_unaligned)
+            .collect())
+    }
+
+    /// Creates a 16-byte array holding the UUID in big-endian byte order, as defined by RFC 9562.
+    ///
+    /// This is more compact than the `String` representation used by [`ToGodot`][crate::meta::ToGodot], e.g. for network packets.
+    /// Use [`to_uuid()`][Self::to_uuid] to convert back.
+    #[cfg(feature = "uuid")]
+    #[cfg_attr(published_docs, doc(cfg(feature = "uuid")))]
+    pub fn from_uuid(uuid: uuid::Uuid) -> Self {
+        Self::from(uuid.as_bytes())
+    }
+
+    /// Interprets this array as a UUID in big-endian byte order. See also [`from_uuid()`][Self::from_uuid].
+    ///
+    /// Returns `Err` if the array does not have exactly 16 bytes.
+    #[cfg(feature = "uuid")]
+    #[cfg_attr(published_docs, doc(cfg(feature = "uuid")))]
+    pub fn to_uuid(&self) -> Result<uuid::Uuid, ConvertError> {
+        uuid::Uuid::from_slice(self.as_slice())
+            .map_err(|err| ConvertError::with_error_value(err, self.clone()))
+    }
+
     /// Returns a new `PackedByteArray`, with the data of this array compressed.
     ///
     /// On failure, Godot prints an error and this method returns `Err`. (Note that any empty results coming from Godot are mapped to `Err`
@@ -1109,7 +1209,8 @@ impl PackedByteArray {
 
     /// Returns a new `PackedByteArray`, with the data of this array decompressed.
     ///
-    /// Set `buffer_size` to the size of the uncompressed data.
+    /// Set `buffer_size` to the size of the uncompressed data. If it is too small to hold the entire output, decompression fails.
+    /// If the size is unknown, use [`decompress_dynamic()`][Self::decompress_dynamic] instead.
     ///
     /// On failure, Godot prints an error and this method returns `Err`. (Note that any empty results coming from Godot are mapped to `Err`
     /// in Rust.)
@@ -1159,6 +1260,78 @@ impl PackedByteArray {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Specific API for PackedStringArray
+
+impl PackedStringArray {
+    /// Converts this array to a vector of Rust strings, making a copy of its contents.
+    ///
+    /// Each element is converted straight from the array's storage, without an intermediate `Vec<GString>`.
+    pub fn to_vec_string(&self) -> Vec<String> {
+        self.as_slice().iter().map(GString::to_string).collect()
+    }
+
+    /// Concatenates all elements into a single string, inserting `separator` between each pair of adjacent elements.
+    ///
+    /// An empty array yields an empty string; a single-element array yields that element without any separator.
+    /// Equivalent to GDScript's `separator.join(array)`, see also [`GString::join()`].
+    pub fn join(&self, separator: &str) -> GString {
+        GString::from(separator).join(self)
+    }
+}
+
+/// Creates a `PackedStringArray` from an iterator of Rust strings.
+///
+/// Like the `FromIterator<GString>` impl, this allocates the array up front if the iterator's size is known.
+///
+/// Godot strings are not designed to hold NUL characters; strings containing them may be truncated.
+impl FromIterator<String> for PackedStringArray {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        iter.into_iter().map(GString::from).collect()
+    }
+}
+
+/// Creates a `PackedStringArray` from an iterator of string slices.
+///
+/// See the `FromIterator<String>` impl for details.
+impl<'a> FromIterator<&'a str> for PackedStringArray {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        iter.into_iter().map(GString::from).collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Specific API for PackedInt32Array and PackedInt64Array
+
+impl PackedInt32Array {
+    /// Returns a `PackedInt64Array` with all elements widened to `i64`.
+    ///
+    /// Always succeeds. See [`PackedInt64Array::to_int32()`] for the opposite direction.
+    pub fn to_int64(&self) -> PackedInt64Array {
+        self.as_slice().iter().map(|&v| i64::from(v)).collect()
+    }
+}
+
+impl PackedInt64Array {
+    /// Returns a `PackedInt32Array` with all elements narrowed to `i32`.
+    ///
+    /// Fails if any element lies outside the range of `i32`; the error message contains the index of the first such element.
+    /// Values are never truncated.
+    pub fn to_int32(&self) -> Result<PackedInt32Array, ConvertError> {
+        self.as_slice()
+            .iter()
+            .enumerate()
+            .map(|(index, &value)| {
+                i32::try_from(value).map_err(|_| {
+                    let message =
+                        format!("PackedInt64Array element at index {index} does not fit into i32");
+                    ConvertError::with_kind_value(ErrorKind::Custom(Some(message.into())), value)
+                })
+            })
+            .collect()
+    }
+}
+
 fn populated_or_err(array: PackedByteArray) -> Result<PackedByteArray, ()> {
     if array.is_empty() {
         Err(())
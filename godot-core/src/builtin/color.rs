@@ -10,6 +10,7 @@ use crate::builtin::inner::InnerColor;
 use crate::builtin::math::ApproxEq;
 use crate::builtin::{ColorHsv, GString};
 
+use crate::meta::error::ConvertError;
 use crate::meta::{arg_into_ref, AsArg};
 use godot_ffi as sys;
 use std::ops;
@@ -139,6 +140,16 @@ impl Color {
         InnerColor::from_hsv(h, s, v, 1.0)
     }
 
+    /// Constructs a `Color` from an HSV profile and an alpha value, using Godot's builtin method. See also [`Color::from_hsv`].
+    ///
+    /// The hue wraps around, so `h` values outside `0.0..1.0` are valid: e.g. 1.25 and -0.75 both represent the same hue as 0.25.
+    /// A saturation of 0 yields a shade of gray, regardless of the hue.
+    ///
+    /// _Godot equivalent: `Color.from_hsv(h, s, v, alpha)`_
+    pub fn from_hsva(h: f64, s: f64, v: f64, a: f64) -> Self {
+        InnerColor::from_hsv(h, s, v, a)
+    }
+
     /// Constructs a `Color` from an [OK HSL
     /// profile](https://bottosson.github.io/posts/colorpicker/). The hue (`h`), saturation (`s`),
     /// and lightness (`l`) are typically between 0.0 and 1.0. Alpha is set to 1; use
@@ -206,6 +217,60 @@ impl Color {
         self.a = from_u8(a);
     }
 
+    /// Returns the HSV hue of this color, in the range `0.0..1.0`.
+    ///
+    /// Colors without saturation (shades of gray) have no defined hue; like in Godot, 0.0 is returned for them.
+    ///
+    /// _Godot equivalent: `Color.h`_
+    #[doc(alias = "get_h")]
+    pub fn h(self) -> f32 {
+        let min = self.r.min(self.g).min(self.b);
+        let max = self.r.max(self.g).max(self.b);
+        let delta = max - min;
+
+        if delta == 0.0 {
+            return 0.0;
+        }
+
+        let sector = if self.r == max {
+            (self.g - self.b) / delta
+        } else if self.g == max {
+            2.0 + (self.b - self.r) / delta
+        } else {
+            4.0 + (self.r - self.g) / delta
+        };
+
+        let h = sector / 6.0;
+        if h < 0.0 {
+            h + 1.0
+        } else {
+            h
+        }
+    }
+
+    /// Returns the HSV saturation of this color, typically in the range `0.0..=1.0`. Black has a saturation of 0.
+    ///
+    /// _Godot equivalent: `Color.s`_
+    #[doc(alias = "get_s")]
+    pub fn s(self) -> f32 {
+        let min = self.r.min(self.g).min(self.b);
+        let max = self.r.max(self.g).max(self.b);
+
+        if max == 0.0 {
+            0.0
+        } else {
+            (max - min) / max
+        }
+    }
+
+    /// Returns the HSV value (brightness) of this color, which is its largest RGB component.
+    ///
+    /// _Godot equivalent: `Color.v`_
+    #[doc(alias = "get_v")]
+    pub fn v(self) -> f32 {
+        self.r.max(self.g).max(self.b)
+    }
+
     /// Returns the light intensity of the color, as a value between 0.0 and 1.0 (inclusive). This
     /// is useful when determining whether a color is light or dark. Colors with a luminance
     /// smaller than 0.5 can be generally considered dark.
@@ -218,6 +283,9 @@ impl Color {
     }
 
     /// Blends the given color on top of this color, taking its alpha into account.
+    ///
+    /// This is the standard "over" operator: the resulting alpha is `over.a + self.a * (1 - over.a)`, and the RGB components are
+    /// weighted by their respective alphas. Blending two fully transparent colors yields `(0, 0, 0, 0)`.
     #[must_use]
     pub fn blend(self, over: Color) -> Self {
         self.as_inner().blend(over)
@@ -225,6 +293,9 @@ impl Color {
 
     /// Returns the linear interpolation between `self`'s components and `to`'s components. The
     /// interpolation factor `weight` should be between 0.0 and 1.0 (inclusive).
+    ///
+    /// All four components, including alpha, are interpolated. `weight` is not clamped: values outside `[0, 1]` extrapolate, and the
+    /// result may have components outside `[0, 1]`.
     #[must_use]
     pub fn lerp(self, to: Color, weight: f64) -> Self {
         self.as_inner().lerp(to, weight)
@@ -238,6 +309,8 @@ impl Color {
 
     /// Creates a new color resulting by making this color darker by the specified amount (ratio
     /// from 0.0 to 1.0). See also [`lightened`][Self::lightened].
+    ///
+    /// Each RGB component is multiplied by `1 - amount`; alpha is left unchanged. `amount` is not clamped.
     #[must_use]
     pub fn darkened(self, amount: f64) -> Self {
         self.as_inner().darkened(amount)
@@ -245,6 +318,8 @@ impl Color {
 
     /// Creates a new color resulting by making this color lighter by the specified amount, which
     /// should be a ratio from 0.0 to 1.0. See also [`darkened`][Self::darkened].
+    ///
+    /// Each RGB component is moved towards 1.0 by `(1 - c) * amount`; alpha is left unchanged. `amount` is not clamped.
     #[must_use]
     pub fn lightened(self, amount: f64) -> Self {
         self.as_inner().lightened(amount)
@@ -260,6 +335,9 @@ impl Color {
     /// Returns the color converted to the [sRGB](https://en.wikipedia.org/wiki/SRGB) color space.
     /// This method assumes the original color is in the linear color space. See also
     /// [`Color::srgb_to_linear`] which performs the opposite operation.
+    ///
+    /// Uses the piecewise sRGB transfer function (a linear segment near black, a 2.4 power curve
+    /// elsewhere), not a plain gamma of 2.2. The alpha channel is left unchanged.
     #[must_use]
     pub fn linear_to_srgb(self) -> Self {
         self.as_inner().linear_to_srgb()
@@ -268,6 +346,8 @@ impl Color {
     /// Returns the color converted to the linear color space. This method assumes the original
     /// color is in the sRGB color space. See also [`Color::linear_to_srgb`] which performs the
     /// opposite operation.
+    ///
+    /// Like [`Color::linear_to_srgb`], this uses the exact piecewise curve and keeps alpha unchanged.
     #[must_use]
     pub fn srgb_to_linear(self) -> Self {
         self.as_inner().srgb_to_linear()
@@ -275,6 +355,8 @@ impl Color {
 
     /// Returns the HTML color code representation of this color, as 8 lowercase hex digits in the
     /// order `RRGGBBAA`, without the `#` prefix.
+    ///
+    /// Each channel is rounded to 8 bits, so [`Color::from_html`] restores colors created from 8-bit components exactly.
     pub fn to_html(self) -> GString {
         self.as_inner().to_html(true)
     }
@@ -553,6 +635,22 @@ fn to_be_words(mut u: u64) -> [u16; 4] {
     [x, y, z, w]
 }
 
+impl std::str::FromStr for Color {
+    type Err = ConvertError;
+
+    /// Parses an HTML color code (with or without `#`) or a named color constant.
+    ///
+    /// Accepts the same strings as [`Color::from_string`], e.g. `"#ff8000"`, `"ff800080"`, `"red"` or `"lawn-green"`.
+    /// To format a color as HTML code, use [`Color::to_html`]; the `Display` impl uses Godot's `(r, g, b, a)` notation instead.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_string(s).ok_or_else(|| {
+            ConvertError::new(format!(
+                "{s:?} is neither an HTML color code nor a named color"
+            ))
+        })
+    }
+}
+
 impl std::fmt::Display for Color {
     /// Formats `Color` to match Godot's string representation.
     ///
@@ -55,9 +55,10 @@ pub use __prelude_reexport::*;
 /// Math-related functions and traits like [`ApproxEq`][math::ApproxEq].
 pub mod math;
 
-/// Iterator types for arrays and dictionaries.
+/// Iterator types for arrays, dictionaries and variants.
 pub mod iter {
     pub use super::collections::iterators::*;
+    pub use super::variant::iterators::*;
 }
 
 /// Specialized types related to Godot's various string implementations.
@@ -103,6 +104,14 @@ pub mod inner {
     pub use crate::gen::builtin_classes::*;
 }
 
+/// Clean up caches of builtin types at end of usage.
+///
+/// # Safety
+/// Must be called on the main thread. Must not use cached builtin APIs (e.g. `StringName::intern()`) after this call.
+pub(crate) unsafe fn cleanup() {
+    string::cleanup();
+}
+
 pub(crate) fn to_i64(i: usize) -> i64 {
     i.try_into().unwrap()
 }
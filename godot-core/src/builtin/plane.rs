@@ -176,6 +176,7 @@ impl Plane {
     /// The ray starts at position `from` and has direction vector `dir`, i.e. it is unbounded in one direction.
     ///
     /// If no intersection is found (the ray is parallel to the plane or points away from it), `None` will be returned.
+    /// A ray starting on the plane (and not parallel to it) intersects it at `from`.
     #[inline]
     pub fn intersect_ray(self, from: Vector3, dir: Vector3) -> Option<Vector3> {
         let denom: real = self.normal.dot(dir);
@@ -215,6 +216,8 @@ impl Plane {
     }
 
     /// Returns `true` if `point` is located above the plane.
+    ///
+    /// Points exactly on the plane are not considered above it. Use [`contains_point()`][Self::contains_point] to check for those.
     #[inline]
     pub fn is_point_over(self, point: Vector3) -> bool {
         self.normal.dot(point) > self.d
@@ -535,6 +538,30 @@ mod test {
         assert_eq!(xy_plane.intersect_ray(low_pos_z, pos_xy), None);
     }
 
+    #[test]
+    fn test_points_on_plane() {
+        // Plane y = 2, facing up.
+        let plane = Plane::new(Vector3::UP, 2.0);
+        let on_plane = Vector3::new(3.0, 2.0, -1.0);
+        let above = Vector3::new(3.0, 5.0, -1.0);
+
+        assert_eq!(plane.distance_to(on_plane), 0.0);
+        assert_eq!(plane.distance_to(above), 3.0);
+        assert_eq!(plane.distance_to(Vector3::ZERO), -2.0);
+
+        assert!(!plane.is_point_over(on_plane));
+        assert!(plane.contains_point(on_plane, None));
+        assert!(plane.is_point_over(above));
+
+        assert_eq!(plane.project(on_plane), on_plane);
+        assert_eq!(plane.project(above), on_plane);
+
+        // Ray starting on the plane hits it immediately; a parallel ray on the plane does not count as intersection.
+        assert_eq!(plane.intersect_ray(on_plane, Vector3::DOWN), Some(on_plane));
+        assert_eq!(plane.intersect_ray(on_plane, Vector3::RIGHT), None);
+        assert_eq!(plane.intersect_ray(above, Vector3::DOWN), Some(on_plane));
+    }
+
     /// Tests `intersect_segment()`.
     #[test]
     fn test_segment_intersections() {
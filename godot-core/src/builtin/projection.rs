@@ -144,6 +144,9 @@ impl Projection {
     /// Creates a new Projection that projects positions in a frustum with the
     /// given clipping planes.
     ///
+    /// Like in Godot, a degenerate frustum (`right <= left`, `top <= bottom` or `far <= near`)
+    /// results in [`Projection::IDENTITY`].
+    ///
     /// _Godot equivalent: `Projection.create_frustum()`_
     pub fn create_frustum(
         left: real,
@@ -153,6 +156,10 @@ impl Projection {
         near: real,
         far: real,
     ) -> Self {
+        if right <= left || top <= bottom || far <= near {
+            return Self::IDENTITY;
+        }
+
         let dx = right - left;
         let dy = top - bottom;
         let dz = near - far;
@@ -268,6 +275,9 @@ impl Projection {
     /// `flip_fov` determines whether the projection's field of view is flipped
     /// over its diagonal.
     ///
+    /// Like in Godot, degenerate inputs (`near == far` or `aspect == 0`) result in
+    /// [`Projection::IDENTITY`].
+    ///
     /// _Godot equivalent: `Projection.create_perspective()`_
     pub fn create_perspective(
         fov_y: real,
@@ -276,6 +286,10 @@ impl Projection {
         far: real,
         flip_fov: bool,
     ) -> Self {
+        if near == far || aspect == 0.0 {
+            return Self::IDENTITY;
+        }
+
         let mut fov_y = fov_y.to_radians();
         if flip_fov {
             fov_y = ((fov_y * 0.5).tan() / aspect).atan() * 2.0;
@@ -922,6 +936,40 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_perspective_degenerate() {
+        assert_eq!(
+            Projection::create_perspective(90.0, 1.0, 1.0, 1.0, false),
+            Projection::IDENTITY
+        );
+        assert_eq!(
+            Projection::create_perspective(90.0, 0.0, 1.0, 2.0, false),
+            Projection::IDENTITY
+        );
+        assert_eq!(
+            Projection::create_perspective(90.0, 0.0, 1.0, 2.0, true),
+            Projection::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_frustum_degenerate() {
+        const TEST_DATA: [[real; 6]; 4] = [
+            [1.0, 1.0, -1.0, 1.0, 1.0, 2.0],
+            [1.0, -1.0, -1.0, 1.0, 1.0, 2.0],
+            [-1.0, 1.0, 1.0, -1.0, 1.0, 2.0],
+            [-1.0, 1.0, -1.0, 1.0, 2.0, 2.0],
+        ];
+
+        for [left, right, bottom, top, near, far] in TEST_DATA {
+            assert_eq!(
+                Projection::create_frustum(left, right, bottom, top, near, far),
+                Projection::IDENTITY,
+                "frustum: left={left} right={right} bottom={bottom} top={top} near={near} far={far}"
+            );
+        }
+    }
+
     #[test]
     fn test_frustum() {
         const TEST_DATA: [([real; 6], [[real; 4]; 4]); 3] = [
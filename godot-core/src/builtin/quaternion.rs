@@ -87,6 +87,12 @@ impl Quaternion {
         <Self as GlamConv>::Glam::from_rotation_arc(arc_from.to_glam(), arc_to.to_glam()).to_front()
     }
 
+    /// Returns the angle (in radians) of the rotation that takes `self` to `to`, in the range `[0, PI]`.
+    ///
+    /// Since `q` and `-q` represent the same rotation, the result does not depend on the sign of either quaternion.
+    /// Both quaternions are expected to be normalized; otherwise the result is meaningless (but no panic occurs).
+    ///
+    /// _Godot equivalent: `Quaternion.angle_to()`_
     pub fn angle_to(self, to: Self) -> real {
         self.glam2(&to, RQuat::angle_between)
     }
@@ -112,6 +118,12 @@ impl Quaternion {
         self.exp()
     }
 
+    /// Creates a quaternion from Euler angles (in radians), with the order `YXZ`.
+    ///
+    /// This is the order Godot uses by default (e.g. for `Node3D.rotation`). The result is always normalized.
+    /// See [`from_euler_with()`](Self::from_euler_with) for custom angle orders.
+    ///
+    /// _Godot equivalent: `Quaternion.from_euler()`_
     pub fn from_euler(euler: Vector3) -> Self {
         let half_a1 = euler.y * 0.5;
         let half_a2 = euler.x * 0.5;
@@ -131,6 +143,16 @@ impl Quaternion {
         )
     }
 
+    /// Creates a quaternion from Euler angles (in radians), applied in the given `order`.
+    ///
+    /// For the default order `YXZ`, this is equivalent to [`from_euler()`](Self::from_euler). The inverse operation is
+    /// [`get_euler_with()`](Self::get_euler_with).
+    ///
+    /// _Godot equivalent: `Basis.from_euler(euler, order).get_rotation_quaternion()`_
+    pub fn from_euler_with(euler: Vector3, order: EulerOrder) -> Self {
+        Basis::from_euler(order, euler).get_quaternion()
+    }
+
     pub fn get_angle(self) -> real {
         2.0 * self.w.acos()
     }
@@ -168,6 +190,12 @@ impl Quaternion {
         self.get_euler_with(order)
     }
 
+    /// Returns the inverse rotation, such that `q * q.inverse()` is [`IDENTITY`][Self::IDENTITY].
+    ///
+    /// This is the conjugate `(-x, -y, -z, w)`, which is only the inverse for normalized quaternions. Godot reports an error for
+    /// non-normalized ones, while this method returns the conjugate regardless.
+    ///
+    /// _Godot equivalent: `Quaternion.inverse()`_
     pub fn inverse(self) -> Self {
         Self::new(-self.x, -self.y, -self.z, self.w)
     }
@@ -180,10 +208,16 @@ impl Quaternion {
         self.length_squared().approx_eq(&1.0)
     }
 
+    /// Returns the length (magnitude) of the quaternion. Quaternions representing a rotation have length 1.
+    ///
+    /// _Godot equivalent: `Quaternion.length()`_
     pub fn length(self) -> real {
         self.length_squared().sqrt()
     }
 
+    /// Returns the squared length of the quaternion, which is cheaper to compute than [`length()`][Self::length].
+    ///
+    /// _Godot equivalent: `Quaternion.length_squared()`_
     pub fn length_squared(self) -> real {
         self.dot(self)
     }
@@ -193,6 +227,12 @@ impl Quaternion {
         Quaternion::new(v.x, v.y, v.z, 0.0)
     }
 
+    /// Returns a copy of the quaternion scaled to length 1, so it represents a pure rotation.
+    ///
+    /// Floating-point errors accumulate when many rotations are composed; normalizing from time to time keeps the result a valid rotation.
+    ///
+    /// _Godot equivalent: `Quaternion.normalized()`_
+    ///
     /// # Panics
     /// If the quaternion has length of 0.
     pub fn normalized(self) -> Self {
@@ -201,8 +241,15 @@ impl Quaternion {
         self / length
     }
 
+    /// Returns the spherical-linear interpolation between `self` and `to`, by `weight`.
+    ///
+    /// Always interpolates along the shorter arc: if the quaternions lie in opposite hemispheres (negative dot product), `to` is negated
+    /// first, which represents the same rotation. Weights outside `[0, 1]` extrapolate along the same arc.
+    ///
+    /// _Godot equivalent: `Quaternion.slerp()`_
+    ///
     /// # Panics
-    /// If either quaternion is not normalized.
+    /// If either quaternion is not normalized. This includes zero-length quaternions.
     pub fn slerp(self, to: Self, weight: real) -> Self {
         let normalized_inputs = self.ensure_normalized(&[&to]);
         assert!(normalized_inputs, "Slerp requires normalized quaternions");
@@ -210,8 +257,14 @@ impl Quaternion {
         self.as_inner().slerp(to, weight.as_f64())
     }
 
+    /// Returns the spherical-linear interpolation between `self` and `to`, by `weight`, without checking for the shorter arc.
+    ///
+    /// Unlike [`slerp()`][Self::slerp], the interpolation may take the longer way around if the quaternions lie in opposite hemispheres.
+    ///
+    /// _Godot equivalent: `Quaternion.slerpni()`_
+    ///
     /// # Panics
-    /// If either quaternion is not normalized.
+    /// If either quaternion is not normalized. This includes zero-length quaternions.
     pub fn slerpni(self, to: Self, weight: real) -> Self {
         let normalized_inputs = self.ensure_normalized(&[&to]);
         assert!(normalized_inputs, "Slerpni requires normalized quaternions");
@@ -320,6 +373,10 @@ impl SubAssign for Quaternion {
 impl Mul<Quaternion> for Quaternion {
     type Output = Self;
 
+    /// Composes two rotations (Hamilton product).
+    ///
+    /// Like with [`Basis`], the right-hand side is applied first: `(a * b) * v` rotates `v` by `b`, then by `a`.
+    /// The product of two normalized quaternions is normalized, up to floating-point error.
     fn mul(self, other: Quaternion) -> Self {
         // TODO use super::glam?
 
@@ -337,8 +394,11 @@ impl Mul<Vector3> for Quaternion {
 
     /// Applies the quaternion's rotation to the 3D point represented by the vector.
     ///
+    /// Like in Godot, the quaternion must be normalized; a non-unit quaternion would also scale the vector. Call
+    /// [`normalized()`][Quaternion::normalized] beforehand if needed.
+    ///
     /// # Panics
-    /// If the quaternion is not normalized.
+    /// If the quaternion is not normalized (in Debug mode).
     fn mul(self, rhs: Vector3) -> Self::Output {
         Vector3::from_glam(self.to_glam().mul_vec3(rhs.to_glam()))
     }
@@ -453,6 +513,141 @@ impl Neg for Quaternion {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+    use crate::assert_eq_approx;
+    use crate::builtin::real_consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    fn assert_same_rotation(a: Quaternion, b: Quaternion) {
+        // q and -q represent the same rotation.
+        assert!(a.dot(b).abs().approx_eq(&1.0), "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn from_euler_known_rotation() {
+        // 90 degrees around Y.
+        let quat = Quaternion::from_euler(Vector3::new(0.0, FRAC_PI_2, 0.0));
+        assert_eq_approx!(
+            quat,
+            Quaternion::new(0.0, FRAC_PI_4.sin(), 0.0, FRAC_PI_4.cos())
+        );
+        assert!(quat.is_normalized());
+        assert_eq_approx!(
+            Basis::from_quaternion(quat) * Vector3::RIGHT,
+            Vector3::FORWARD
+        );
+
+        let euler = Vector3::new(0.3, -1.2, 0.9);
+        let quat = Quaternion::from_euler(euler);
+        assert!(quat.is_normalized());
+        assert_eq_approx!(quat.get_euler(), euler);
+    }
+
+    #[test]
+    fn from_euler_with_order() {
+        // All angles within (-PI/2, PI/2), so that get_euler_with() returns the same angles for every order.
+        let euler = Vector3::new(0.3, -1.2, 0.9);
+
+        // Default order is YXZ.
+        assert_same_rotation(
+            Quaternion::from_euler_with(euler, EulerOrder::YXZ),
+            Quaternion::from_euler(euler),
+        );
+
+        for order in [
+            EulerOrder::XYZ,
+            EulerOrder::XZY,
+            EulerOrder::YXZ,
+            EulerOrder::YZX,
+            EulerOrder::ZXY,
+            EulerOrder::ZYX,
+        ] {
+            let quat = Quaternion::from_euler_with(euler, order);
+            assert!(quat.is_normalized(), "{order:?}");
+            assert_eq_approx!(
+                Basis::from_quaternion(quat),
+                Basis::from_euler(order, euler),
+                "{order:?}"
+            );
+            assert_eq_approx!(quat.get_euler_with(order), euler, "{order:?}");
+        }
+
+        // Orders differ if more than one angle is non-zero.
+        assert!(!Quaternion::from_euler_with(euler, EulerOrder::XYZ)
+            .approx_eq(&Quaternion::from_euler_with(euler, EulerOrder::ZYX)));
+    }
+
+    #[test]
+    fn mul_rotates_vector() {
+        let quarter_y = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+        assert_eq_approx!(quarter_y * Vector3::RIGHT, Vector3::FORWARD);
+        assert_eq_approx!(quarter_y * Vector3::UP, Vector3::UP);
+        assert_eq_approx!(quarter_y.inverse() * Vector3::FORWARD, Vector3::RIGHT);
+
+        // Same result as the equivalent basis.
+        let quat = Quaternion::from_euler(Vector3::new(0.3, -1.2, 0.9));
+        let v = Vector3::new(1.0, -2.0, 0.5);
+        assert_eq_approx!(quat * v, Basis::from_quaternion(quat) * v);
+        assert_eq_approx!((quat * v).length(), v.length());
+    }
+
+    #[test]
+    fn mul_composes_rotations() {
+        let quarter_y = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+        let quarter_x = Quaternion::from_axis_angle(Vector3::RIGHT, FRAC_PI_2);
+        let v = Vector3::new(1.0, 2.0, 3.0);
+
+        // Two quarter turns make a half turn.
+        assert_same_rotation(
+            quarter_y * quarter_y,
+            Quaternion::from_axis_angle(Vector3::UP, PI),
+        );
+        assert_eq_approx!((quarter_y * quarter_y) * Vector3::RIGHT, Vector3::LEFT);
+
+        // Right-hand side is applied first; composition is not commutative.
+        assert_eq_approx!((quarter_y * quarter_x) * v, quarter_y * (quarter_x * v));
+        assert!(!((quarter_x * quarter_y) * v).approx_eq(&((quarter_y * quarter_x) * v)));
+
+        let mut composed = quarter_y;
+        composed *= quarter_x;
+        assert_eq_approx!(composed, quarter_y * quarter_x);
+        assert!(composed.is_normalized());
+
+        assert_eq_approx!(quarter_y * quarter_y.inverse(), Quaternion::IDENTITY);
+        assert_eq_approx!(Quaternion::IDENTITY * quarter_x, quarter_x);
+    }
+
+    #[test]
+    fn length_normalized() {
+        let quat = Quaternion::new(1.0, 2.0, 2.0, 4.0);
+        assert_eq_approx!(quat.length_squared(), 25.0);
+        assert_eq_approx!(quat.length(), 5.0);
+        assert!(!quat.is_normalized());
+
+        let normalized = quat.normalized();
+        assert_eq_approx!(normalized, Quaternion::new(0.2, 0.4, 0.4, 0.8));
+        assert_eq_approx!(normalized.length(), 1.0);
+        assert!(normalized.is_normalized());
+    }
+
+    #[test]
+    #[should_panic]
+    fn normalized_zero() {
+        let _ = Quaternion::new(0.0, 0.0, 0.0, 0.0).normalized();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn mul_vector_non_normalized() {
+        let _ = Quaternion::new(1.0, 2.0, 2.0, 4.0) * Vector3::RIGHT;
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_axis_angle_non_normalized() {
+        let _ = Quaternion::from_axis_angle(Vector3::new(0.0, 3.0, 0.0), 1.0);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
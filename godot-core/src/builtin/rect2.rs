@@ -137,6 +137,9 @@ impl Rect2 {
     }
 
     /// Returns a copy of the Rect2 grown by the specified `amount` on all sides.
+    ///
+    /// A negative `amount` shrinks the rectangle. Like in Godot, the result is not clamped: shrinking by more than half the size yields
+    /// a rectangle with negative size, which can be normalized with [`abs()`][Self::abs].
     #[inline]
     #[must_use]
     pub fn grow(self, amount: real) -> Self {
@@ -147,6 +150,8 @@ impl Rect2 {
     }
 
     /// Returns a copy of the Rect2 grown by the specified amount on each side individually.
+    ///
+    /// Negative amounts move the respective side inwards; see [`grow()`][Self::grow].
     #[inline]
     pub fn grow_individual(self, left: real, top: real, right: real, bottom: real) -> Self {
         Self::from_components(
@@ -196,10 +201,15 @@ impl Rect2 {
         self.contains_point(point)
     }
 
-    /// Returns the intersection of this Rect2 and `b`. If the rectangles do not intersect, an empty Rect2 is returned.
+    /// Returns the intersection of this `Rect2` and `b`.
+    ///
+    /// If the rectangles do not intersect, `None` is returned.
+    ///
+    /// Like in Godot, rectangles that only share a border do not intersect. Note that this differs from
+    /// [`intersects()`][Self::intersects], which includes borders.
     #[inline]
     pub fn intersect(self, b: Self) -> Option<Self> {
-        if !self.intersects(b) {
+        if !self.intersects_exclude_borders(b) {
             return None;
         }
 
@@ -320,6 +330,100 @@ impl std::fmt::Display for Rect2 {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+
+    #[test]
+    fn intersect() {
+        let a = Rect2::from_components(0.0, 0.0, 4.0, 4.0);
+
+        // Overlapping.
+        let b = Rect2::from_components(2.0, 1.0, 4.0, 2.0);
+        assert_eq!(
+            a.intersect(b),
+            Some(Rect2::from_components(2.0, 1.0, 2.0, 2.0))
+        );
+        assert_eq!(b.intersect(a), a.intersect(b));
+
+        // Enclosed.
+        let c = Rect2::from_components(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(a.intersect(c), Some(c));
+
+        // Touching edge or corner: no intersection, although `intersects()` is true.
+        let edge = Rect2::from_components(4.0, 0.0, 2.0, 2.0);
+        let corner = Rect2::from_components(4.0, 4.0, 1.0, 1.0);
+        assert_eq!(a.intersect(edge), None);
+        assert_eq!(a.intersect(corner), None);
+        assert!(a.intersects(edge));
+
+        // Disjoint.
+        let d = Rect2::from_components(10.0, 10.0, 1.0, 1.0);
+        assert_eq!(a.intersect(d), None);
+    }
+
+    #[test]
+    fn merge() {
+        let a = Rect2::from_components(0.0, 0.0, 4.0, 4.0);
+        let b = Rect2::from_components(2.0, -1.0, 4.0, 2.0);
+        assert_eq!(a.merge(b), Rect2::from_components(0.0, -1.0, 6.0, 5.0));
+        assert_eq!(b.merge(a), a.merge(b));
+
+        let d = Rect2::from_components(10.0, 10.0, 1.0, 1.0);
+        assert_eq!(a.merge(d), Rect2::from_components(0.0, 0.0, 11.0, 11.0));
+        assert_eq!(a.merge(a), a);
+    }
+
+    #[test]
+    fn grow() {
+        let rect = Rect2::from_components(1.0, 2.0, 4.0, 6.0);
+
+        assert_eq!(rect.grow(1.0), Rect2::from_components(0.0, 1.0, 6.0, 8.0));
+        assert_eq!(rect.grow(-1.0), Rect2::from_components(2.0, 3.0, 2.0, 4.0));
+        assert_eq!(rect.grow(0.0), rect);
+
+        // Shrinking past the center produces a negative size, same as Godot.
+        let inverted = rect.grow(-3.0);
+        assert_eq!(inverted, Rect2::from_components(4.0, 5.0, -2.0, 0.0));
+        assert!(!inverted.has_area());
+        assert_eq!(inverted.abs(), Rect2::from_components(2.0, 5.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn grow_individual() {
+        let rect = Rect2::from_components(1.0, 2.0, 4.0, 6.0);
+
+        assert_eq!(
+            rect.grow_individual(1.0, 2.0, 3.0, 4.0),
+            Rect2::from_components(0.0, 0.0, 8.0, 12.0)
+        );
+        assert_eq!(
+            rect.grow_individual(-1.0, 0.0, 0.0, -2.0),
+            Rect2::from_components(2.0, 2.0, 3.0, 4.0)
+        );
+        assert_eq!(rect.grow_individual(1.0, 1.0, 1.0, 1.0), rect.grow(1.0));
+        assert_eq!(
+            rect.grow_side(Side::RIGHT, 2.0),
+            rect.grow_individual(0.0, 0.0, 2.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn expand() {
+        let rect = Rect2::from_components(0.0, 0.0, 4.0, 4.0);
+
+        // Point inside: unchanged.
+        assert_eq!(rect.expand(Vector2::new(2.0, 2.0)), rect);
+
+        // Points outside, on either side.
+        assert_eq!(
+            rect.expand(Vector2::new(6.0, 1.0)),
+            Rect2::from_components(0.0, 0.0, 6.0, 4.0)
+        );
+        assert_eq!(
+            rect.expand(Vector2::new(-2.0, -3.0)),
+            Rect2::from_components(-2.0, -3.0, 6.0, 7.0)
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
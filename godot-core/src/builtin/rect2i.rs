@@ -107,6 +107,10 @@ impl Rect2i {
 
     /// Returns a `Rect2i` with equivalent position and area, modified so that the top-left corner
     /// is the origin and `width` and `height` are positive.
+    ///
+    /// The covered area stays the same: e.g. position `(1, 2)` with size `(-3, -4)` becomes position `(-2, -2)` with size `(3, 4)`.
+    ///
+    /// _Godot equivalent: `Rect2i.abs` function_
     #[inline]
     pub fn abs(self) -> Self {
         let abs_size = self.size.abs();
@@ -117,7 +121,12 @@ impl Rect2i {
     /// Returns `true` if this `Rect2i` completely encloses another one.
     ///
     /// Any `Rect2i` encloses itself, i.e. an enclosed `Rect2i` does is not required to be a
-    /// proper sub-rect.
+    /// proper sub-rect. Unlike [`contains_point()`][Self::contains_point], sharing the right or bottom edge still counts as enclosed.
+    ///
+    /// # Panics
+    /// If `self.size` or `other.size` is negative. Use [`abs()`][Self::abs] to normalize such rects first.
+    ///
+    /// _Godot equivalent: `Rect2i.encloses` function_
     #[inline]
     pub const fn encloses(self, other: Self) -> bool {
         self.assert_nonnegative();
@@ -204,6 +213,9 @@ impl Rect2i {
     /// Returns `true` if the `Rect2i` contains a point. By convention, the right and bottom edges
     /// of the `Rect2i` are considered exclusive, so points on these edges are not included.
     ///
+    /// # Panics
+    /// If `self.size` is negative. Use [`abs()`][Self::abs] to normalize such rects first.
+    ///
     /// _Godot equivalent: `Rect2i.has_point` function_
     #[doc(alias = "has_point")]
     #[inline]
@@ -461,6 +473,53 @@ mod test {
         rect.expand(Vector2i::ZERO);
     }
 
+    #[test]
+    fn contains_point_edges() {
+        let rect = Rect2i::from_components(2, 3, 4, 5); // Covers x in 2..6, y in 3..8.
+
+        // Top-left corner and left/top edges are inclusive.
+        assert!(rect.contains_point(Vector2i::new(2, 3)));
+        assert!(rect.contains_point(Vector2i::new(2, 7)));
+        assert!(rect.contains_point(Vector2i::new(5, 3)));
+        assert!(rect.contains_point(Vector2i::new(5, 7)));
+
+        // Right/bottom edges are exclusive.
+        assert!(!rect.contains_point(Vector2i::new(6, 3)));
+        assert!(!rect.contains_point(Vector2i::new(2, 8)));
+        assert!(!rect.contains_point(Vector2i::new(6, 8)));
+
+        // Just outside left/top.
+        assert!(!rect.contains_point(Vector2i::new(1, 3)));
+        assert!(!rect.contains_point(Vector2i::new(2, 2)));
+
+        // Negative-size rect covering the same cells, after normalization.
+        let flipped = Rect2i::from_components(6, 8, -4, -5);
+        assert_eq!(flipped.abs(), rect);
+        assert!(flipped.abs().contains_point(Vector2i::new(2, 3)));
+        assert!(!flipped.abs().contains_point(Vector2i::new(6, 8)));
+    }
+
+    #[test]
+    fn encloses_edges() {
+        let outer = Rect2i::from_components(0, 0, 10, 10);
+
+        // Sharing edges with the outer rect is allowed.
+        assert!(outer.encloses(Rect2i::from_components(0, 0, 10, 1)));
+        assert!(outer.encloses(Rect2i::from_components(9, 9, 1, 1)));
+        assert!(outer.encloses(Rect2i::from_components(10, 10, 0, 0)));
+
+        // Extending one cell past any edge is not.
+        assert!(!outer.encloses(Rect2i::from_components(-1, 0, 2, 2)));
+        assert!(!outer.encloses(Rect2i::from_components(0, -1, 2, 2)));
+        assert!(!outer.encloses(Rect2i::from_components(9, 0, 2, 2)));
+        assert!(!outer.encloses(Rect2i::from_components(0, 9, 2, 2)));
+
+        // Negative-size rects must be normalized first.
+        let flipped = Rect2i::from_components(10, 10, -10, -10);
+        assert!(outer.encloses(flipped.abs()));
+        assert!(flipped.abs().encloses(outer));
+    }
+
     #[test]
     #[should_panic]
     fn contains_point_self_negative_panics() {
@@ -498,6 +557,25 @@ mod test {
         assert_eq!(d.center(), Vector2i::new(8, 9));
     }
 
+    #[test]
+    fn intersect_and_merge() {
+        let a = Rect2i::from_components(0, 0, 4, 4);
+        let overlapping = Rect2i::from_components(2, 1, 4, 2);
+        let touching = Rect2i::from_components(4, 0, 2, 2);
+        let disjoint = Rect2i::from_components(10, 10, 1, 1);
+
+        assert_eq!(
+            a.intersect(overlapping),
+            Some(Rect2i::from_components(2, 1, 2, 2))
+        );
+        assert_eq!(a.intersect(touching), None);
+        assert_eq!(a.intersect(disjoint), None);
+
+        assert_eq!(a.merge(overlapping), Rect2i::from_components(0, 0, 6, 4));
+        assert_eq!(a.merge(touching), Rect2i::from_components(0, 0, 6, 4));
+        assert_eq!(a.merge(disjoint), Rect2i::from_components(0, 0, 11, 11));
+    }
+
     #[test]
     fn grow() {
         let a = Rect2i::from_components(3, 3, 4, 4);
@@ -66,6 +66,14 @@ impl Rid {
         }
     }
 
+    /// Create a RID from its numeric ID, as returned by [`to_u64()`][Self::to_u64].
+    ///
+    /// An ID of 0 results in [`Rid::Invalid`]. Equivalent to [`Rid::new()`].
+    #[inline]
+    pub const fn from_u64(id: u64) -> Self {
+        Self::new(id)
+    }
+
     /// Convert this RID into a [`u64`]. Returns 0 if it is invalid.
     ///
     /// _Godot equivalent: `Rid.get_id()`_
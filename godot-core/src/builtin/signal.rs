@@ -11,12 +11,13 @@ use std::ptr;
 use godot_ffi as sys;
 
 use crate::builtin::{inner, Array, Callable, Dictionary, StringName, Variant};
+use crate::classes::object::ConnectFlags;
 use crate::classes::Object;
 use crate::global::Error;
 use crate::meta;
 use crate::meta::{FromGodot, GodotType, ToGodot};
 use crate::obj::bounds::DynMemory;
-use crate::obj::{Bounds, Gd, GodotClass, InstanceId};
+use crate::obj::{Bounds, EngineBitfield, Gd, GodotClass, InstanceId};
 use sys::{ffi_methods, GodotFfi};
 
 /// A `Signal` represents a signal of an Object instance in Godot.
@@ -76,12 +77,26 @@ impl Signal {
     /// returns [`Error::ERR_INVALID_PARAMETER`] and
     /// pushes an error message, unless the signal is connected with [`ConnectFlags::REFERENCE_COUNTED`](crate::classes::object::ConnectFlags::REFERENCE_COUNTED).
     /// To prevent this, use [`Self::is_connected`] first to check for existing connections.
+    ///
+    /// See also [`connect_flags()`][Self::connect_flags], which accepts typed flags.
     pub fn connect(&self, callable: &Callable, flags: i64) -> Error {
         let error = self.as_inner().connect(callable, flags);
 
         Error::from_godot(error as i32)
     }
 
+    /// Connects this signal to the specified callable, with typed [`ConnectFlags`].
+    ///
+    /// Multiple flags can be combined with `|`, e.g. `ConnectFlags::DEFERRED | ConnectFlags::ONE_SHOT`. Use [`ConnectFlags::default()`]
+    /// for a regular connection. One-shot connections are removed automatically after the first emission.
+    ///
+    /// The same rules as in [`connect()`][Self::connect] apply: connecting an already connected callable returns
+    /// [`Error::ERR_INVALID_PARAMETER`], unless [`ConnectFlags::REFERENCE_COUNTED`] is used. In the latter case, the callable must be
+    /// disconnected as many times as it was connected.
+    pub fn connect_flags(&self, callable: &Callable, flags: ConnectFlags) -> Error {
+        self.connect(callable, flags.ord() as i64)
+    }
+
     /// Disconnects this signal from the specified [`Callable`].
     ///
     /// If the connection does not exist, generates an error. Use [`Self::is_connected`] to make sure that the connection exists.
@@ -7,7 +7,6 @@
 
 use std::convert::Infallible;
 use std::fmt;
-use std::fmt::Write;
 
 use godot_ffi as sys;
 use sys::types::OpaqueString;
@@ -16,7 +15,7 @@ use sys::{ffi_methods, interface_fn, GodotFfi};
 use crate::builtin::string::Encoding;
 use crate::builtin::{inner, NodePath, StringName, Variant};
 use crate::meta::error::StringError;
-use crate::meta::AsArg;
+use crate::meta::{AsArg, ToGodot};
 use crate::{impl_shared_string_api, meta};
 
 /// Godot's reference counted string type.
@@ -54,6 +53,13 @@ use crate::{impl_shared_string_api, meta};
 /// Note that Godot ignores any bytes after a null-byte. This means that for instance `"hello, world!"` and `"hello, world!\0 ignored by Godot"`
 /// will be treated as the same string if converted to a `GString`.
 ///
+/// # File paths
+///
+/// For paths used by Godot APIs, such as `res://` and `user://` paths, prefer Godot's path helpers like `path_join()`, `get_file()`,
+/// `get_extension()`, `get_basename()` and `get_base_dir()` over `std::path`. They always use `/` as separator, keep the `res://`
+/// prefix intact, and follow Godot's rules -- e.g. `".gitignore".get_extension()` is `"gitignore"`, whereas Rust's `Path::extension()`
+/// returns `None`.
+///
 /// # All string types
 ///
 /// | Intended use case | String type                                |
@@ -179,6 +185,39 @@ impl GString {
         }
     }
 
+    /// Borrows the string's UTF-32 buffer as a character slice, without copying.
+    ///
+    /// The slice points directly into Godot's storage and lives as long as `&self`. It does not include the trailing NUL
+    /// terminator; an empty string yields an empty slice.
+    ///
+    /// This is the same as [`chars()`][Self::chars], named for symmetry with Rust's `as_slice()`/`as_str()` accessors.
+    #[inline]
+    pub fn as_char_slice(&self) -> &[char] {
+        self.chars()
+    }
+
+    /// Formats the string like GDScript's `%` operator, with C-style placeholders such as `%s`, `%d`, `%05.2f` or `%x`.
+    ///
+    /// `values` is either an array with one element per placeholder, or a single non-array value for exactly one placeholder.
+    /// Any value can be substituted into `%s`, using its [`stringify()`][Variant::stringify] representation. Returns `None` if there are
+    /// fewer or more values than placeholders, or if a value does not match its placeholder (e.g. a string for `%d`).
+    ///
+    /// For `{}`-style placeholders, see [`format()`][Self::format].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let text = GString::from("%s has %d items").sprintf(&varray!["Bag", 3].to_variant());
+    /// assert_eq!(text, Some("Bag has 3 items".into()));
+    /// ```
+    ///
+    /// _Godot equivalent: `string % values`_
+    pub fn sprintf(&self, values: &Variant) -> Option<GString> {
+        self.to_variant()
+            .evaluate(values, crate::builtin::VariantOperator::MODULE)
+            .map(|result| result.to())
+    }
+
     ffi_methods! {
         type sys::GDExtensionStringPtr = *mut Self;
 
@@ -298,14 +337,32 @@ impl_shared_string_api! {
 
 impl fmt::Display for GString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Encode into a stack buffer and flush it in chunks, rather than calling `write_char()` once per character.
+        // Needs no heap allocation, and no UTF-8 validation as in `String::from(&GString)`.
+        let mut buf = [0u8; 256];
+        let mut len = 0;
+
         for ch in self.chars() {
-            f.write_char(*ch)?;
+            if len + ch.len_utf8() > buf.len() {
+                // SAFETY: `buf[..len]` consists of whole `encode_utf8()` sequences.
+                f.write_str(unsafe { utf8_chunk(&buf[..len]) })?;
+                len = 0;
+            }
+
+            len += ch.encode_utf8(&mut buf[len..]).len();
         }
 
-        Ok(())
+        // SAFETY: `buf[..len]` consists of whole `encode_utf8()` sequences.
+        f.write_str(unsafe { utf8_chunk(&buf[..len]) })
     }
 }
 
+/// # Safety
+/// `bytes` must be valid UTF-8, i.e. only contain complete sequences written by `char::encode_utf8()`.
+unsafe fn utf8_chunk(bytes: &[u8]) -> &str {
+    std::str::from_utf8_unchecked(bytes)
+}
+
 /// Uses literal syntax from GDScript: `"string"`
 impl fmt::Debug for GString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -94,6 +94,28 @@ impl NodePath {
         inner.get_subname(index)
     }
 
+    /// Returns the node name at position `index`, or `None` if `index` is out of bounds.
+    ///
+    /// Non-panicking version of [`get_name()`][Self::get_name].
+    pub fn try_get_name(&self, index: usize) -> Option<StringName> {
+        if index < self.get_name_count() {
+            Some(self.as_inner().get_name(index as i64))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the node subname (property) at position `index`, or `None` if `index` is out of bounds.
+    ///
+    /// Non-panicking version of [`get_subname()`][Self::get_subname].
+    pub fn try_get_subname(&self, index: usize) -> Option<StringName> {
+        if index < self.get_subname_count() {
+            Some(self.as_inner().get_subname(index as i64))
+        } else {
+            None
+        }
+    }
+
     /// Returns the number of node names in the path. Property subnames are not included.
     pub fn get_name_count(&self) -> usize {
         self.as_inner()
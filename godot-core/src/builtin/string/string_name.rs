@@ -4,6 +4,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt;
 
 use godot_ffi as sys;
@@ -15,6 +17,21 @@ use crate::meta::error::StringError;
 use crate::meta::AsArg;
 use crate::{impl_shared_string_api, meta};
 
+thread_local! {
+    // Cache for StringName::intern(). Must be cleared on deinitialization, as names don't survive an engine restart or hot-reload.
+    // Only filled on the main thread: deinitialization runs there and cannot reach the thread-locals of other threads, whose
+    // entries would otherwise be destroyed at thread exit, possibly after Godot is gone.
+    static INTERNED_NAMES: RefCell<HashMap<&'static str, StringName>> = RefCell::default();
+}
+
+/// # Safety
+/// Must be called on the main thread. Must not use `StringName::intern()` after this call.
+pub(crate) unsafe fn cleanup() {
+    INTERNED_NAMES.with_borrow_mut(HashMap::clear);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// A string optimized for unique names.
 ///
 /// StringNames are immutable strings designed for representing unique names. StringName ensures that only
@@ -39,6 +56,15 @@ use crate::{impl_shared_string_api, meta};
 /// can be used directly by Godot, without allocation or conversion. The encoding is limited to Latin-1, however. See the corresponding
 /// [`From<&'static CStr>` impl](#impl-From<%26CStr>-for-StringName).
 ///
+/// If you need the full UTF-8 range and repeatedly construct the same name on the main thread (e.g. method or property names in
+/// `process()`), [`StringName::intern()`] caches the name. After the first call, it returns a copy of the cached instance instead of
+/// decoding the UTF-8 string and looking it up in Godot's global name table again.
+///
+/// Godot does not expose the characters of a `StringName` directly, so converting to Rust strings goes through a temporary [`GString`].
+/// For most names, this only increments the reference count of the name's internal string; names created from `c"..."` literals need
+/// to allocate a copy instead. Formatting (`Display`, `to_string()`, `format!`) then encodes directly into the output, while
+/// [`String::from(&name)`](#impl-From<%26StringName>-for-String) lets Godot produce a UTF-8 buffer, which is then validated.
+///
 /// # All string types
 ///
 /// | Intended use case | String type                                |
@@ -138,6 +164,38 @@ impl StringName {
         }
     }
 
+    /// Returns a `StringName` for a `'static` string, using a cache for repeated calls.
+    ///
+    /// The first call for a given string constructs the name and stores it; subsequent calls only clone the cached instance, which
+    /// is a reference-count increment. The result is equal to `StringName::from(string)`. The cache lives until the
+    /// library is unloaded, so only use this for a bounded set of names.
+    ///
+    /// The cache is thread-local, so lookups never contend for a lock. It is only used on the main thread, where the cache can be
+    /// released when the library is unloaded; on other threads, this function is equivalent to `StringName::from(string)`.
+    /// Work is only saved when the same name is requested repeatedly on the main thread; a name needed once costs an extra cache
+    /// insertion. No speedup over `StringName::from()` is guaranteed, so measure in your own code if it matters.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// for _ in 0..1000 {
+    ///     let name = StringName::intern("position");
+    ///     // ...
+    /// }
+    /// ```
+    pub fn intern(string: &'static str) -> Self {
+        #[cfg(not(wasm_nothreads))]
+        if !sys::is_main_thread() {
+            return Self::from(string);
+        }
+
+        INTERNED_NAMES.with_borrow_mut(|names| {
+            names
+                .entry(string)
+                .or_insert_with(|| Self::from(string))
+                .clone()
+        })
+    }
+
     /// Number of characters in the string.
     ///
     /// _Godot equivalent: `length`_
@@ -295,6 +353,7 @@ impl_shared_string_api! {
 
 impl fmt::Display for StringName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Shares the name's buffer in most cases, see "Performance" section in type docs.
         let s = GString::from(self);
         <GString as fmt::Display>::fmt(&s, f)
     }
@@ -58,6 +58,7 @@ pub struct Transform2D {
     /// starts at this point.
     ///
     /// _Godot equivalent: `Transform2D.origin`_
+    #[doc(alias = "get_origin")]
     pub origin: Vector2,
 }
 
@@ -160,9 +161,17 @@ impl Transform2D {
     /// Returns the inverse of the transform, under the assumption that the
     /// transformation is composed of rotation, scaling and translation.
     ///
+    /// If the basis is not invertible (its [`determinant()`][Self::determinant] is exactly zero), there is no inverse. Like Godot,
+    /// which reports an error in this case, the transform is then returned unchanged.
+    ///
     /// _Godot equivalent: `Transform2D.affine_inverse()`_
     #[must_use]
     pub fn affine_inverse(&self) -> Self {
+        // Check explicitly: glam asserts an invertible matrix in debug builds, and would return non-finite values otherwise.
+        if self.determinant() == 0.0 {
+            return *self;
+        }
+
         self.glam(|aff| aff.inverse())
     }
 
@@ -178,16 +187,27 @@ impl Transform2D {
         self.basis().determinant()
     }
 
-    /// Returns the transform's rotation (in radians).
+    /// Returns the transform's rotation (in radians), in the range `-PI..=PI`.
+    ///
+    /// This is the angle of the first basis vector [`a`][Self::a]. If the transform is flipped along the X axis, the flip is reported
+    /// as a rotation by `PI` combined with a negative Y scale; see [`scale()`][Self::scale].
     ///
     /// _Godot equivalent: `Transform2D.get_rotation()`_
+    #[doc(alias = "get_rotation")]
     pub fn rotation(&self) -> real {
         self.basis().rotation()
     }
 
-    /// Returns the transform's scale.
+    /// Returns the transform's scale, i.e. the lengths of the basis vectors.
+    ///
+    /// A flip (negative [`determinant()`][Self::determinant]) cannot be attributed to a specific axis, so like in Godot, it is always
+    /// reported as a negative Y component. For a degenerate basis with zero determinant, the Y component is 0.
+    ///
+    /// [`rotation()`][Self::rotation], `scale()`, [`skew()`][Self::skew] and [`origin`][Self::origin] can be passed to
+    /// [`from_angle_scale_skew_origin()`][Self::from_angle_scale_skew_origin] to reconstruct a non-degenerate transform.
     ///
     /// _Godot equivalent: `Transform2D.get_scale()`_
+    #[doc(alias = "get_scale")]
     #[must_use]
     pub fn scale(&self) -> Vector2 {
         self.basis().scale()
@@ -195,7 +215,10 @@ impl Transform2D {
 
     /// Returns the transform's skew (in radians).
     ///
+    /// This is the deviation of the angle between the basis vectors from a right angle. If either basis vector is zero, the skew is 0.
+    ///
     /// _Godot equivalent: `Transform2D.get_skew()`_
+    #[doc(alias = "get_skew")]
     #[must_use]
     pub fn skew(&self) -> real {
         self.basis().skew()
@@ -304,6 +327,8 @@ impl Transform2D {
     /// Returns a vector transformed (multiplied) by the basis matrix.
     /// This method does not account for translation (the origin vector).
     ///
+    /// To transform a point including translation (Godot's `xform`), use `transform * v`.
+    ///
     /// _Godot equivalent: `Transform2D.basis_xform()`_
     pub fn basis_xform(&self, v: Vector2) -> Vector2 {
         self.to_basis() * v
@@ -459,7 +484,8 @@ impl Basis2D {
     /// Returns the scale of the matrix.
     #[must_use]
     pub(crate) fn scale(&self) -> Vector2 {
-        let det_sign = self.determinant().signum();
+        // Godot's SIGN() is 0 for 0, unlike signum().
+        let det_sign = self.determinant().sign();
         Vector2::new(self.cols[0].length(), det_sign * self.cols[1].length())
     }
 
@@ -507,11 +533,11 @@ impl Basis2D {
     /// Returns the skew of the matrix
     #[must_use]
     pub(crate) fn skew(&self) -> real {
-        // Translated from Godot
-        let det_sign = self.determinant().signum();
+        // Translated from Godot, where normalizing a zero vector yields zero.
+        let det_sign = self.determinant().sign();
         self.cols[0]
-            .normalized()
-            .dot(det_sign * self.cols[1].normalized())
+            .normalized_or_zero()
+            .dot(det_sign * self.cols[1].normalized_or_zero())
             .acos()
             - PI * 0.5
     }
@@ -757,6 +783,107 @@ mod test {
         );
     }
 
+    #[test]
+    fn affine_inverse_undoes_rotation_translation() {
+        let transform =
+            Transform2D::from_angle_origin(real!(30.0).to_radians(), Vector2::new(3.0, -2.0));
+        let point = Vector2::new(1.0, 0.0);
+
+        let expected = Vector2::new(
+            real!(30.0).to_radians().cos(),
+            real!(30.0).to_radians().sin(),
+        ) + Vector2::new(3.0, -2.0);
+        assert_eq_approx!(transform * point, expected);
+        assert_eq_approx!(
+            transform.basis_xform(point),
+            expected - Vector2::new(3.0, -2.0)
+        );
+
+        let inverse = transform.affine_inverse();
+        assert_eq_approx!(inverse * (transform * point), point);
+        assert_eq_approx!(transform * inverse, Transform2D::IDENTITY);
+        assert_eq_approx!(inverse.rotation(), real!(-30.0).to_radians());
+        assert_eq_approx!(
+            transform.basis_xform_inv(transform.basis_xform(point)),
+            point
+        );
+    }
+
+    #[test]
+    fn decomposition() {
+        let angle = real!(30.0).to_radians();
+        let skew = real!(10.0).to_radians();
+        let scale = Vector2::new(2.0, 0.5);
+        let origin = Vector2::new(-4.0, 7.5);
+
+        let transform = Transform2D::IDENTITY
+            .scaled(scale)
+            .rotated(angle)
+            .translated(origin);
+        assert_eq_approx!(transform.rotation(), angle);
+        assert_eq_approx!(transform.scale(), scale);
+        assert_eq_approx!(transform.skew(), 0.0);
+        assert_eq_approx!(transform.origin, origin);
+
+        let transform = Transform2D::from_angle_scale_skew_origin(angle, scale, skew, origin);
+        assert_eq_approx!(transform.rotation(), angle);
+        assert_eq_approx!(transform.scale(), scale);
+        assert_eq_approx!(transform.skew(), skew);
+        assert_eq_approx!(transform.origin, origin);
+    }
+
+    #[test]
+    fn decomposition_flipped() {
+        let angle = real!(30.0).to_radians();
+        let transform = Transform2D::from_angle_scale_skew_origin(
+            angle,
+            Vector2::new(-2.0, 3.0),
+            0.0,
+            Vector2::ZERO,
+        );
+
+        // Flip along X is reported as rotation by PI and negative Y scale.
+        assert_eq_approx!(transform.rotation(), angle - PI);
+        assert_eq_approx!(transform.scale(), Vector2::new(2.0, -3.0));
+        assert_eq_approx!(transform.skew(), 0.0);
+
+        let rebuilt = Transform2D::from_angle_scale_skew_origin(
+            transform.rotation(),
+            transform.scale(),
+            transform.skew(),
+            transform.origin,
+        );
+        assert_eq_approx!(rebuilt, transform);
+    }
+
+    #[test]
+    fn decomposition_degenerate() {
+        let transform = Transform2D::from_cols(
+            Vector2::new(2.0, 0.0),
+            Vector2::ZERO,
+            Vector2::new(1.0, 1.0),
+        );
+        assert_eq!(transform.rotation(), 0.0);
+        assert_eq!(transform.scale(), Vector2::new(2.0, 0.0));
+        assert_eq_approx!(transform.skew(), 0.0);
+
+        let transform = Transform2D::from_cols(Vector2::ZERO, Vector2::ZERO, Vector2::ZERO);
+        assert_eq!(transform.rotation(), 0.0);
+        assert_eq!(transform.scale(), Vector2::ZERO);
+        assert_eq_approx!(transform.skew(), 0.0);
+    }
+
+    #[test]
+    fn affine_inverse_non_invertible() {
+        let transform = Transform2D::from_cols(
+            Vector2::new(1.0, 2.0),
+            Vector2::new(2.0, 4.0),
+            Vector2::ZERO,
+        );
+        assert_eq!(transform.determinant(), 0.0);
+        assert_eq!(transform.affine_inverse(), transform);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -135,8 +135,19 @@ impl Transform3D {
 
     /// Returns a transform interpolated between this transform and another by
     /// a given weight (on the range of 0.0 to 1.0).
+    ///
+    /// Scale and origin are interpolated linearly, rotation is interpolated spherically (see [`Quaternion::slerp()`]).
+    /// A weight of exactly 0.0 or 1.0 returns `self` or `other` unchanged, without any rounding errors from decomposition.
+    ///
+    /// _Godot equivalent: `Transform3D.interpolate_with()`_
     #[must_use]
     pub fn interpolate_with(&self, other: &Self, weight: real) -> Self {
+        if weight == 0.0 {
+            return *self;
+        } else if weight == 1.0 {
+            return *other;
+        }
+
         let src_scale = self.basis.get_scale();
         let src_rot = self.basis.get_quaternion().normalized();
         let src_loc = self.origin;
@@ -160,6 +171,19 @@ impl Transform3D {
         self.basis.is_finite() && self.origin.is_finite()
     }
 
+    /// Returns a copy of the transform, rotated so that its forward axis points towards the position `target`.
+    ///
+    /// The origin is kept. To build a camera transform from an eye position, start from a translation:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let eye = Vector3::new(0.0, 2.0, 5.0);
+    /// let camera = Transform3D::new(Basis::IDENTITY, eye).looking_at(Vector3::ZERO, Vector3::UP, false);
+    /// ```
+    ///
+    /// Forward is -Z, or +Z if `use_model_front` is true. For the degenerate cases `target == self.origin` and `up` parallel to the view
+    /// direction, see [`Basis::looking_at()`].
+    ///
+    /// _Godot equivalent: `Transform3D.looking_at()`_
     #[must_use]
     pub fn looking_at(&self, target: Vector3, up: Vector3, use_model_front: bool) -> Self {
         Self {
@@ -23,12 +23,19 @@ use godot_ffi as sys;
 // that requires the pointer to be initialized. But some other types will cause a memory leak in 4.1 if initialized.
 //
 // Therefore, we can use `init` to indicate when it must be initialized in 4.0.
+//
+// `scalar` types don't get an `Option<T>` impl: they are foreign, so it would conflict with the blanket impl for nullable FFI types.
 macro_rules! impl_ffi_variant {
     (ref $T:ty, $from_fn:ident, $to_fn:ident $(; $GodotTy:ident)?) => {
         impl_ffi_variant!(@impls by_ref; $T, $from_fn, $to_fn $(; $GodotTy)?);
+        impl_ffi_variant!(@option $T);
+    };
+    (scalar $T:ty, $from_fn:ident, $to_fn:ident $(; $GodotTy:ident)?) => {
+        impl_ffi_variant!(@impls by_val; $T, $from_fn, $to_fn $(; $GodotTy)?);
     };
     ($T:ty, $from_fn:ident, $to_fn:ident $(; $GodotTy:ident)?) => {
         impl_ffi_variant!(@impls by_val; $T, $from_fn, $to_fn $(; $GodotTy)?);
+        impl_ffi_variant!(@option $T);
     };
 
     // Implementations
@@ -83,9 +90,68 @@ macro_rules! impl_ffi_variant {
 
         impl ArrayElement for $T {}
 
+        impl TryFrom<&Variant> for $T {
+            type Error = ConvertError;
+
+            fn try_from(variant: &Variant) -> Result<Self, Self::Error> {
+                <$T as crate::meta::FromGodot>::try_from_variant(variant)
+            }
+        }
+
+        // Conversion only needs a reference, so the owned variant is simply dropped afterward.
+        impl TryFrom<Variant> for $T {
+            type Error = ConvertError;
+
+            fn try_from(variant: Variant) -> Result<Self, Self::Error> {
+                Self::try_from(&variant)
+            }
+        }
+
         impl_ffi_variant!(@as_arg $by_ref_or_val $T);
     };
 
+    // Builtin types are not nullable on the FFI level, so `Option<T>` is passed as `Variant`: `None` maps to `NIL`, `Some(value)` to
+    // the variant of `value`. The conversion traits are provided by the generic `Option<T>` impls.
+    (@option $T:ty) => {
+        impl GodotType for Option<$T> {
+            type Ffi = Variant;
+            type ToFfi<'a> = Variant;
+
+            fn to_ffi(&self) -> Self::ToFfi<'_> {
+                crate::meta::ToGodot::to_variant(self)
+            }
+
+            fn into_ffi(self) -> Self::Ffi {
+                crate::meta::ToGodot::to_variant(&self)
+            }
+
+            fn try_from_ffi(ffi: Self::Ffi) -> Result<Self, ConvertError> {
+                crate::meta::FromGodot::try_from_variant(&ffi)
+            }
+
+            // Declared as Variant, so Godot accepts null, but with the hint of the inner type.
+            fn property_info(property_name: &str) -> PropertyInfo {
+                PropertyInfo {
+                    hint_info: <$T as GodotType>::property_hint_info(),
+                    ..<Variant as GodotType>::property_info(property_name)
+                }
+            }
+
+            fn property_hint_info() -> PropertyHintInfo {
+                <$T as GodotType>::property_hint_info()
+            }
+
+            fn godot_type_name() -> String {
+                <Variant as GodotType>::godot_type_name()
+            }
+        }
+
+        // Godot cannot store null in typed arrays, so `Array<Option<T>>` is an untyped array on the Godot side.
+        impl ArrayElement for Option<$T> {}
+
+        $crate::meta::impl_asarg_by_ref!(Option<$T>);
+    };
+
     (@godot_type_name $T:ty) => {
         fn godot_type_name() -> String {
             stringify!($T).into()
@@ -134,9 +200,9 @@ mod impls {
     // IMPORTANT: the presence/absence of `ref` here should be aligned with the ArgPassing variant
     // used in codegen get_builtin_arg_passing().
 
-    impl_ffi_variant!(bool, bool_to_variant, bool_from_variant);
-    impl_ffi_variant!(i64, int_to_variant, int_from_variant; int);
-    impl_ffi_variant!(f64, float_to_variant, float_from_variant; float);
+    impl_ffi_variant!(scalar bool, bool_to_variant, bool_from_variant);
+    impl_ffi_variant!(scalar i64, int_to_variant, int_from_variant; int);
+    impl_ffi_variant!(scalar f64, float_to_variant, float_from_variant; float);
     impl_ffi_variant!(Vector2, vector2_to_variant, vector2_from_variant);
     impl_ffi_variant!(Vector3, vector3_to_variant, vector3_from_variant);
     impl_ffi_variant!(Vector4, vector4_to_variant, vector4_from_variant);
@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use std::{fmt, ptr};
+use sys::{interface_fn, SysPtr};
+
+use crate::builtin::Variant;
+
+/// Iterator over the elements of an iterable [`Variant`], following the same protocol as GDScript's `for` loop.
+///
+/// Created by [`Variant::iter()`]. Each element is yielded as a `Variant`.
+///
+/// Like GDScript, the iterator does not operate on a snapshot: modifying the iterated container through another reference during
+/// iteration is memory-safe, but the yielded elements are unspecified.
+pub struct VariantIter {
+    iterable: Variant,
+    state: Variant,
+    status: Status,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Status {
+    /// `state` points to the element to be yielded next.
+    Current,
+
+    /// `state` points to the last yielded element, and must be advanced first.
+    Advance,
+
+    /// Iteration has ended.
+    Done,
+}
+
+impl VariantIter {
+    /// Starts iteration. Returns `None` if `iterable` does not support Godot's iteration protocol.
+    pub(super) fn new(iterable: Variant) -> Option<Self> {
+        // Godot assigns to the state variant, so it must be initialized (see also Dictionary iteration).
+        let mut state = Variant::nil();
+        let mut valid = 0u8;
+
+        // SAFETY: `iterable` and `state` are valid variants.
+        let has_next = unsafe {
+            interface_fn!(variant_iter_init)(
+                iterable.var_sys(),
+                state.var_sys_mut().as_uninit(),
+                ptr::addr_of_mut!(valid),
+            )
+        };
+
+        if valid == 0 {
+            return None;
+        }
+
+        let status = if has_next != 0 {
+            Status::Current
+        } else {
+            Status::Done
+        };
+
+        Some(Self {
+            iterable,
+            state,
+            status,
+        })
+    }
+
+    fn advance(&mut self) -> bool {
+        let mut valid = 0u8;
+
+        // SAFETY: `iterable` and `state` are valid variants; `state` was produced by a previous iteration call.
+        let has_next = unsafe {
+            interface_fn!(variant_iter_next)(
+                self.iterable.var_sys(),
+                self.state.var_sys_mut(),
+                ptr::addr_of_mut!(valid),
+            )
+        };
+
+        valid != 0 && has_next != 0
+    }
+
+    fn get_current(&mut self) -> Option<Variant> {
+        let mut element = Variant::nil();
+        let mut valid = 0u8;
+
+        // SAFETY: all variants are valid; `element` is initialized for the same reason as `state` in new().
+        unsafe {
+            interface_fn!(variant_iter_get)(
+                self.iterable.var_sys(),
+                self.state.var_sys_mut(),
+                element.var_sys_mut().as_uninit(),
+                ptr::addr_of_mut!(valid),
+            )
+        };
+
+        (valid != 0).then_some(element)
+    }
+}
+
+impl Iterator for VariantIter {
+    type Item = Variant;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Advance lazily, so that the body of a loop runs before the next step -- same order as in GDScript.
+        match self.status {
+            Status::Done => return None,
+            Status::Advance if !self.advance() => {
+                self.status = Status::Done;
+                return None;
+            }
+            _ => {}
+        }
+
+        let element = self.get_current();
+        self.status = if element.is_some() {
+            Status::Advance
+        } else {
+            Status::Done
+        };
+
+        element
+    }
+}
+
+impl std::iter::FusedIterator for VariantIter {}
+
+impl fmt::Debug for VariantIter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VariantIter")
+            .field("iterable", &self.iterable)
+            .field("done", &(self.status == Status::Done))
+            .finish()
+    }
+}
@@ -5,16 +5,27 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::math::ApproxEq;
 use crate::builtin::{
-    GString, StringName, VariantArray, VariantDispatch, VariantOperator, VariantType,
+    Dictionary, GString, NodePath, PackedByteArray, StringName, VariantArray, VariantDispatch,
+    VariantOperator, VariantType,
 };
-use crate::meta::error::ConvertError;
-use crate::meta::{arg_into_ref, ArrayElement, AsArg, FromGodot, ToGodot};
+use crate::meta::error::{CallError, ConvertError, ErrorKind};
+use crate::meta::{arg_into_ref, ArrayElement, AsArg, CallContext, FromGodot, ToGodot};
 use godot_ffi as sys;
+use std::collections::HashSet;
 use std::{fmt, ptr};
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 mod impls;
+mod iter;
+
+use iter::VariantIter;
+
+// Re-export in godot::builtin::iter.
+pub(crate) mod iterators {
+    pub use super::iter::VariantIter;
+}
 
 /// Godot variant type, able to store a variety of different types.
 ///
@@ -68,6 +79,138 @@ impl Variant {
         T::try_from_variant(self)
     }
 
+    /// Convert to type `T`, returning `None` on failure.
+    ///
+    /// Equivalent to [`try_to()`][Self::try_to], but discards the error. Use this if you only care whether the conversion succeeded.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let variant = Vector2::new(1.0, 2.0).to_variant();
+    /// assert_eq!(variant.get::<Vector2>(), Some(Vector2::new(1.0, 2.0)));
+    ///
+    /// let variant = GString::from("hello").to_variant();
+    /// assert_eq!(variant.get::<GString>(), Some(GString::from("hello")));
+    ///
+    /// // Type mismatch.
+    /// assert_eq!(variant.get::<Vector2>(), None);
+    ///
+    /// // Nil converts to unit.
+    /// assert_eq!(Variant::nil().get::<()>(), Some(()));
+    /// ```
+    #[inline]
+    pub fn get<T: FromGodot>(&self) -> Option<T> {
+        T::try_from_variant(self).ok()
+    }
+
+    /// Convert to type `T`, allowing the implicit type coercions that Godot performs. Returns `Err` on failure.
+    ///
+    /// Unlike [`try_to()`][Self::try_to], which requires the variant to hold exactly `T`'s Godot type, this follows the conversion rules of
+    /// GDScript/C++ `Variant`. For example:
+    /// - `INT` <-> `FLOAT` <-> `BOOL` (floats are truncated towards zero).
+    /// - `STRING` -> `INT`/`FLOAT`, using Godot's lenient number parsing (`""` becomes `0`).
+    ///
+    /// Conversions that Godot does not support (e.g. `NIL` -> `INT`, `VECTOR2` -> `FLOAT`) return an error. Additionally, `FLOAT` -> `INT`
+    /// fails for NaN, infinity and values outside the `i64` range, since Godot's result is undefined in these cases.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let variant = 42.to_variant();
+    /// assert!(variant.try_to::<f64>().is_err());
+    /// assert_eq!(variant.try_to_relaxed::<f64>().unwrap(), 42.0);
+    /// ```
+    pub fn try_to_relaxed<T: FromGodot>(&self) -> Result<T, ConvertError> {
+        crate::meta::try_from_variant_relaxed(self)
+    }
+
+    /// Converts this variant to a variant of type `target`, using Godot's constructors.
+    ///
+    /// This corresponds to GDScript's conversion syntax such as `int(x)`, `String(x)` or `Vector2i(x)`, and supports everything Godot's
+    /// constructors accept: e.g. `FLOAT` -> `INT` (truncating), `STRING` -> `INT`/`FLOAT` (parsing), `ARRAY` -> `PACKED_INT32_ARRAY` or
+    /// `VECTOR2` -> `VECTOR2I`. If the variant already has type `target`, a copy is returned.
+    ///
+    /// Unlike [`try_to_relaxed()`][Self::try_to_relaxed], the result stays a `Variant`, so the target type can be chosen at runtime.
+    ///
+    /// Returns `Err` if Godot has no constructor for `target` taking the current type (e.g. `DICTIONARY` -> `VECTOR2`). Like with
+    /// `try_to_relaxed()`, `FLOAT` -> `INT` additionally fails for NaN, infinity and values outside the `i64` range.
+    ///
+    /// _Godot equivalent: `type_convert(variant, type)` (for constructible types)_
+    pub fn convert(&self, target: VariantType) -> Result<Variant, ConvertError> {
+        use crate::meta::error::FromVariantError;
+
+        let from_type = self.get_type();
+        if from_type == target {
+            return Ok(self.clone());
+        }
+
+        let bad_type = || {
+            FromVariantError::BadType {
+                expected: target,
+                actual: from_type,
+            }
+            .into_error(self.clone())
+        };
+
+        // See try_from_variant_relaxed(): C++ cast from f64 to i64 is undefined for these values.
+        if from_type == VariantType::FLOAT && target == VariantType::INT {
+            let value = self.to::<f64>();
+            if !(value >= i64::MIN as f64 && value < i64::MAX as f64) {
+                return Err(FromVariantError::BadValue.into_error(value));
+            }
+        }
+
+        // There are no constructors for NIL taking an argument.
+        if target == VariantType::NIL {
+            return Err(bad_type());
+        }
+
+        let args_sys = [self.var_sys()];
+        let mut error = sys::default_call_error();
+
+        // SAFETY: variant_construct initializes the result (with nil on error).
+        let result = unsafe {
+            Self::new_with_var_uninit(|variant_ptr| {
+                interface_fn!(variant_construct)(
+                    target.sys(),
+                    variant_ptr,
+                    args_sys.as_ptr(),
+                    args_sys.len() as i32,
+                    ptr::addr_of_mut!(error),
+                )
+            })
+        };
+
+        if error.error != sys::GDEXTENSION_CALL_OK {
+            return Err(bad_type());
+        }
+
+        Ok(result)
+    }
+
+    /// Checks whether this variant's type can be implicitly converted to `target`, without converting anything.
+    ///
+    /// Uses Godot's conversion table, which also decides whether an argument is accepted when calling a method (e.g. `INT` for a `float`
+    /// parameter) -- so dispatch code can validate arguments up-front. These are the same coercions that
+    /// [`try_to_relaxed()`][Self::try_to_relaxed] performs. Identical types are always convertible.
+    ///
+    /// This is a check on the type only, not the value: `"abc"` is convertible to `INT` just like `"42"`, and a `FLOAT` holding NaN is
+    /// convertible to `INT`, even though `try_to_relaxed()` rejects it.
+    ///
+    /// Unlike Godot, which considers every type convertible to `NIL`, this returns `false` for `NIL` targets unless the variant is
+    /// already nil -- matching [`convert()`][Self::convert], which has no way to produce a nil from another type.
+    ///
+    /// _Godot equivalent: `Variant::can_convert(from, to)` (C++)_
+    pub fn can_convert_to(&self, target: VariantType) -> bool {
+        if target == VariantType::NIL {
+            return self.is_nil();
+        }
+
+        // SAFETY: plain query on two type enumerators.
+        let result =
+            unsafe { interface_fn!(variant_can_convert)(self.get_type().sys(), target.sys()) };
+
+        result == sys::conv::SYS_TRUE
+    }
+
     /// Checks whether the variant is empty (`null` value in GDScript).
     ///
     /// See also [`get_type()`][Self::get_type].
@@ -106,6 +249,239 @@ impl Variant {
         }
     }
 
+    /// Returns the Godot name of the type currently held by this variant, e.g. `"Vector2"` or `"Nil"`.
+    ///
+    /// Equivalent to [`self.get_type().type_name()`][VariantType::type_name]. Useful for logging and error messages.
+    ///
+    /// _Godot equivalent: `type_string(typeof(variant))`_
+    pub fn type_name(&self) -> &'static str {
+        self.get_type().type_name()
+    }
+
+    /// For variants holding a container, returns its number of elements.
+    ///
+    /// Supported are `Array` (typed or untyped), `Dictionary` (number of key-value pairs) and all packed arrays. Other types, including
+    /// strings, return `None`.
+    ///
+    /// _Godot equivalent: `container.size()`_
+    pub fn size(&self) -> Option<usize> {
+        use VariantType as T;
+
+        match self.get_type() {
+            T::ARRAY
+            | T::DICTIONARY
+            | T::PACKED_BYTE_ARRAY
+            | T::PACKED_INT32_ARRAY
+            | T::PACKED_INT64_ARRAY
+            | T::PACKED_FLOAT32_ARRAY
+            | T::PACKED_FLOAT64_ARRAY
+            | T::PACKED_STRING_ARRAY
+            | T::PACKED_VECTOR2_ARRAY
+            | T::PACKED_VECTOR3_ARRAY
+            | T::PACKED_COLOR_ARRAY => {}
+            #[cfg(since_api = "4.3")]
+            T::PACKED_VECTOR4_ARRAY => {}
+            _ => return None,
+        }
+
+        let size = self.call("size", &[]).to::<i64>();
+        Some(size as usize)
+    }
+
+    /// Iterates over this variant like GDScript's `for` loop would, yielding each element as a `Variant`.
+    ///
+    /// Supports all values that GDScript can iterate: integers and floats (counting from 0), integer/float vectors (ranges, as in
+    /// `range()`), strings (characters), arrays and packed arrays (elements), dictionaries (keys), and objects implementing
+    /// `_iter_init()`, `_iter_next()` and `_iter_get()`.
+    ///
+    /// Returns an error if the variant is not iterable, e.g. `nil`, `bool` or `Color`. For containers with a known type, prefer their own
+    /// iterators such as [`Array::iter_shared()`][crate::builtin::Array::iter_shared], which avoid the per-element FFI roundtrips.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let count = 3.to_variant();
+    /// let numbers: Vec<i64> = count.iter().unwrap().map(|v| v.to::<i64>()).collect();
+    /// assert_eq!(numbers, [0, 1, 2]);
+    /// ```
+    pub fn iter(&self) -> Result<VariantIter, ConvertError> {
+        VariantIter::new(self.clone()).ok_or_else(|| {
+            let message = format!("Variant of type {} is not iterable", self.type_name());
+            ConvertError::with_kind_value(ErrorKind::Custom(Some(message.into())), self.clone())
+        })
+    }
+
+    /// Describes the first structural difference between two variants, or returns `None` if they are equal.
+    ///
+    /// Meant for test assertions on nested containers, where a plain `assert_eq!` prints two large values without hinting at the
+    /// mismatch. Arrays are compared element by element and then by length; dictionaries by key, in insertion order of `self`. Other
+    /// values are compared with `==`.
+    ///
+    /// The description starts with the path to the difference, such as `["items"][2]["name"]`, or `<root>` for the top level.
+    /// For example: `["items"][2]: type INT != STRING (3 vs "3")`, or `["tags"]: array length 2 != 3`.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let expected = dict! { "pos": varray![1, 2] }.to_variant();
+    /// let actual = dict! { "pos": varray![1, 3] }.to_variant();
+    ///
+    /// if let Some(diff) = actual.diff(&expected) {
+    ///     panic!("mismatch at {diff}"); // mismatch at ["pos"][1]: 3 != 2
+    /// }
+    /// ```
+    pub fn diff(&self, other: &Variant) -> Option<GString> {
+        Self::diff_at(self, other, "").map(|diff| GString::from(&diff))
+    }
+
+    fn diff_at(lhs: &Variant, rhs: &Variant, path: &str) -> Option<String> {
+        let location = if path.is_empty() { "<root>" } else { path };
+
+        let (lhs_type, rhs_type) = (lhs.get_type(), rhs.get_type());
+        if lhs_type != rhs_type {
+            return Some(format!(
+                "{location}: type {lhs_type:?} != {rhs_type:?} ({lhs:?} vs {rhs:?})"
+            ));
+        }
+
+        match lhs_type {
+            VariantType::ARRAY => {
+                // SAFETY: type is checked, and arrays are only read (no covariant element access), same as in Debug impl.
+                let (lhs, rhs) = unsafe {
+                    (
+                        VariantArray::from_variant_unchecked(lhs),
+                        VariantArray::from_variant_unchecked(rhs),
+                    )
+                };
+
+                for (index, (l, r)) in lhs.iter_shared().zip(rhs.iter_shared()).enumerate() {
+                    if let Some(diff) = Self::diff_at(&l, &r, &format!("{path}[{index}]")) {
+                        return Some(diff);
+                    }
+                }
+
+                if lhs.len() != rhs.len() {
+                    return Some(format!(
+                        "{location}: array length {} != {}",
+                        lhs.len(),
+                        rhs.len()
+                    ));
+                }
+
+                None
+            }
+
+            VariantType::DICTIONARY => {
+                let lhs = lhs.to::<Dictionary>();
+                let rhs = rhs.to::<Dictionary>();
+
+                for (key, l) in lhs.iter_shared() {
+                    let key_path = format!("{path}[{key:?}]");
+                    match rhs.get(key) {
+                        Some(r) => {
+                            if let Some(diff) = Self::diff_at(&l, &r, &key_path) {
+                                return Some(diff);
+                            }
+                        }
+                        None => return Some(format!("{key_path}: only in left ({l:?})")),
+                    }
+                }
+
+                rhs.iter_shared()
+                    .find(|(key, _)| !lhs.contains_key(key.clone()))
+                    .map(|(key, r)| format!("{path}[{key:?}]: only in right ({r:?})"))
+            }
+
+            _ if lhs != rhs => Some(format!("{location}: {lhs:?} != {rhs:?}")),
+            _ => None,
+        }
+    }
+
+    /// Deep equality check that terminates on self-referential containers.
+    ///
+    /// Godot's `==` compares arrays and dictionaries recursively, but gives up with an error once a maximum recursion depth is
+    /// reached -- which any cyclic structure (e.g. an array containing itself) runs into. This method instead tracks which pairs of
+    /// containers are already being compared, identified by instance (see `is_same()` in GDScript). When such a pair is encountered
+    /// again, it is considered equal for the purpose of the ongoing comparison. Two structures are thus equal if no difference can be
+    /// found by walking them in lockstep, even if their cycles have different shapes.
+    ///
+    /// Arrays are compared element by element, dictionaries by key lookup. Container keys in dictionaries are looked up with Godot's
+    /// own hashing, so they must not be cyclic themselves. Other values are compared with `==`.
+    ///
+    /// Note that arrays and dictionaries are reference-counted, so a container that (directly or indirectly) contains itself is
+    /// never freed. Break the cycle once you no longer need it, e.g. by calling `clear()` on the container.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let mut a = varray![1];
+    /// a.push(&a.to_variant()); // a = [1, a]
+    ///
+    /// let mut b = varray![1];
+    /// b.push(&b.to_variant()); // b = [1, b]
+    ///
+    /// assert!(a.to_variant().recursive_equal(&b.to_variant()));
+    ///
+    /// // Break the cycles, otherwise both arrays leak.
+    /// a.clear();
+    /// b.clear();
+    /// ```
+    pub fn recursive_equal(&self, other: &Variant) -> bool {
+        Self::recursive_equal_at(self, other, &mut HashSet::new())
+    }
+
+    fn recursive_equal_at(
+        lhs: &Variant,
+        rhs: &Variant,
+        visited: &mut HashSet<(usize, usize)>,
+    ) -> bool {
+        let ty = lhs.get_type();
+        if ty != rhs.get_type() {
+            return false;
+        }
+
+        if ty == VariantType::ARRAY {
+            // SAFETY: type is checked, and arrays are only read (no covariant element access), same as in Debug impl.
+            let (lhs, rhs) = unsafe {
+                (
+                    VariantArray::from_variant_unchecked(lhs),
+                    VariantArray::from_variant_unchecked(rhs),
+                )
+            };
+
+            // Pairs are never removed: a pair that was fully compared is equal (otherwise the whole comparison would have returned
+            // false already), so this also skips shared substructure compared before.
+            if !visited.insert((Self::storage_address(&lhs), Self::storage_address(&rhs))) {
+                return true;
+            }
+
+            lhs.len() == rhs.len()
+                && lhs
+                    .iter_shared()
+                    .zip(rhs.iter_shared())
+                    .all(|(l, r)| Self::recursive_equal_at(&l, &r, visited))
+        } else if ty == VariantType::DICTIONARY {
+            let lhs = lhs.to::<Dictionary>();
+            let rhs = rhs.to::<Dictionary>();
+
+            if !visited.insert((Self::storage_address(&lhs), Self::storage_address(&rhs))) {
+                return true;
+            }
+
+            lhs.len() == rhs.len()
+                && lhs.iter_shared().all(|(key, l)| match rhs.get(key) {
+                    Some(r) => Self::recursive_equal_at(&l, &r, visited),
+                    None => false,
+                })
+        } else {
+            lhs == rhs
+        }
+    }
+
+    /// Identifies an array or dictionary instance, like `is_same()` in GDScript.
+    fn storage_address<C: GodotFfi>(container: &C) -> usize {
+        // SAFETY: Array and Dictionary consist of a single pointer to their reference-counted storage (ArrayPrivate/DictionaryPrivate),
+        // which is shared between all references to the same instance.
+        unsafe { *container.sys().cast::<usize>() }
+    }
+
     /// For variants holding an object, returns the object's instance ID.
     ///
     /// If the variant is not an object, returns `None`.
@@ -162,6 +538,8 @@ impl Variant {
     ///
     /// Supports `Object` as well as built-ins with methods (e.g. `Array`, `Vector3`, `GString`, etc.).
     ///
+    /// To handle errors gracefully, use [`try_call()`][Self::try_call] instead.
+    ///
     /// # Panics
     /// * If `self` is not a variant type which supports method calls.
     /// * If the method does not exist or the signature is not compatible with the passed arguments.
@@ -169,10 +547,42 @@ impl Variant {
     #[inline]
     pub fn call(&self, method: impl AsArg<StringName>, args: &[Variant]) -> Variant {
         arg_into_ref!(method);
-        self.call_inner(method, args)
+
+        let (result, error) = self.call_inner(method, args);
+        if error.error != sys::GDEXTENSION_CALL_OK {
+            let arg_types: Vec<_> = args.iter().map(Variant::get_type).collect();
+            sys::panic_call_error(&error, "call", &arg_types);
+        }
+        result
+    }
+
+    /// Calls the specified `method` with the given `args`, returning `Err` on failure.
+    ///
+    /// Supports `Object` as well as built-ins with methods (e.g. `Array`, `Vector3`, `GString`, etc.).
+    ///
+    /// Fails if the method does not exist on the variant's type (including types without any methods, such as `NIL`), if the
+    /// arguments do not match the method's signature, or if the call itself causes an error.
+    ///
+    /// _Godot equivalent: `variant.call(method, ...)`_
+    #[inline]
+    pub fn try_call(
+        &self,
+        method: impl AsArg<StringName>,
+        args: &[Variant],
+    ) -> Result<Variant, CallError> {
+        arg_into_ref!(method);
+
+        let (result, error) = self.call_inner(method, args);
+        let call_ctx = CallContext::outbound(self.type_name(), "call");
+
+        CallError::check_out_varcall(&call_ctx, error, &[method.clone()], args).map(|()| result)
     }
 
-    fn call_inner(&self, method: &StringName, args: &[Variant]) -> Variant {
+    fn call_inner(
+        &self,
+        method: &StringName,
+        args: &[Variant],
+    ) -> (Variant, sys::GDExtensionCallError) {
         let args_sys: Vec<_> = args.iter().map(|v| v.var_sys()).collect();
         let mut error = sys::default_call_error();
 
@@ -189,11 +599,132 @@ impl Variant {
             })
         };
 
-        if error.error != sys::GDEXTENSION_CALL_OK {
-            let arg_types: Vec<_> = args.iter().map(Variant::get_type).collect();
-            sys::panic_call_error(&error, "call", &arg_types);
+        (result, error)
+    }
+
+    /// Returns the value at a nested property path, such as `"position:x"` for a `Node2D` or `"x"` for a `Vector2`.
+    ///
+    /// Each path segment accesses a named member of the previous value: properties of objects, or components of built-in types such as
+    /// vectors, rects and colors. The path is interpreted as a property path (see `NodePath.get_as_property_path()`), so node names and
+    /// subnames are treated alike.
+    ///
+    /// Returns `Err` if the path is empty or one of its segments does not exist on the value it is applied to.
+    ///
+    /// _Godot equivalent: `Object.get_indexed()`, extended to all variant types_
+    pub fn get_indexed(&self, path: impl AsArg<NodePath>) -> Result<Variant, ConvertError> {
+        arg_into_ref!(path);
+
+        let names = Self::property_path_names(path)?;
+
+        let mut current = self.clone();
+        for name in &names {
+            current = current
+                .get_named(name)
+                .ok_or_else(|| Self::indexed_error("cannot get", name, path, &current))?;
         }
-        result
+
+        Ok(current)
+    }
+
+    /// Assigns `value` at a nested property path, such as `"position:x"` for a `Node2D` or `"x"` for a `Vector2`.
+    ///
+    /// Built-in types like vectors are stored by value, so all intermediate values along the path are written back, up to `self`.
+    /// See [`get_indexed()`][Self::get_indexed] for the path syntax.
+    ///
+    /// Returns `Err` if the path is empty, one of its segments does not exist, or `value` has a type that cannot be assigned to the
+    /// last segment. In that case, `self` is left unchanged (but objects along the path may already have been modified).
+    ///
+    /// _Godot equivalent: `Object.set_indexed()`, extended to all variant types_
+    pub fn set_indexed(
+        &mut self,
+        path: impl AsArg<NodePath>,
+        value: &Variant,
+    ) -> Result<(), ConvertError> {
+        arg_into_ref!(path);
+
+        let names = Self::property_path_names(path)?;
+
+        // chain[i] is the value that holds the member names[i].
+        let mut chain = Vec::with_capacity(names.len());
+        chain.push(self.clone());
+        for name in &names[..names.len() - 1] {
+            let holder = chain.last().expect("chain is never empty");
+            let next = holder
+                .get_named(name)
+                .ok_or_else(|| Self::indexed_error("cannot get", name, path, holder))?;
+            chain.push(next);
+        }
+
+        let mut new_value = value.clone();
+        for (holder, name) in chain.iter_mut().zip(&names).rev() {
+            if !holder.set_named(name, &new_value) {
+                return Err(Self::indexed_error("cannot set", name, path, holder));
+            }
+            new_value = holder.clone();
+        }
+
+        *self = new_value;
+        Ok(())
+    }
+
+    fn property_path_names(path: &NodePath) -> Result<Vec<StringName>, ConvertError> {
+        let property_path = path.get_as_property_path();
+        let names: Vec<StringName> = (0..property_path.get_subname_count())
+            .map(|i| property_path.get_subname(i))
+            .collect();
+
+        if names.is_empty() {
+            return Err(ConvertError::new("property path must not be empty"));
+        }
+
+        Ok(names)
+    }
+
+    fn indexed_error(
+        action: &str,
+        name: &StringName,
+        path: &NodePath,
+        holder: &Variant,
+    ) -> ConvertError {
+        let message = format!(
+            "{action} member `{name}` of {} in property path `{path}`",
+            holder.type_name()
+        );
+
+        ConvertError::with_kind_value(ErrorKind::Custom(Some(message.into())), holder.clone())
+    }
+
+    fn get_named(&self, name: &StringName) -> Option<Variant> {
+        let mut is_valid = false as u8;
+
+        // Godot always initializes the result, with nil if the member is invalid.
+        let result = unsafe {
+            Self::new_with_var_uninit(|variant_ptr| {
+                interface_fn!(variant_get_named)(
+                    self.var_sys(),
+                    name.string_sys(),
+                    variant_ptr,
+                    ptr::addr_of_mut!(is_valid),
+                )
+            })
+        };
+
+        (is_valid != 0).then_some(result)
+    }
+
+    fn set_named(&mut self, name: &StringName, value: &Variant) -> bool {
+        let mut is_valid = false as u8;
+
+        unsafe {
+            interface_fn!(variant_set_named)(
+                self.var_sys_mut(),
+                name.string_sys(),
+                value.var_sys(),
+                ptr::addr_of_mut!(is_valid),
+            )
+        };
+
+        is_valid != 0
     }
 
     /// Evaluates an expression using a GDScript operator.
@@ -236,7 +767,12 @@ impl Variant {
 
     /// Return Godot's string representation of the variant.
     ///
-    /// See also `Display` impl.
+    /// This is the same output as GDScript's `str()` and `print()`: top-level strings are not quoted, while strings nested in arrays
+    /// and dictionaries are, e.g. `["text", { "key": 1 }]`.
+    ///
+    /// See also `Display` impl, which forwards to this method.
+    ///
+    /// _Godot equivalent: `str(variant)`_
     #[allow(unused_mut)] // result
     pub fn stringify(&self) -> GString {
         let mut result = GString::new();
@@ -246,11 +782,52 @@ impl Variant {
         result
     }
 
-    /// Return Godot's hash value for the variant.
+    /// Returns a 32-bit integer hash value representing the variant and its contents.
+    ///
+    /// Equal values of the same type always produce identical hashes; the reverse is not true due to collisions. Values are hashed
+    /// according to their dynamic type, so an `int` and a `float` may compare equal (`1 == 1.0`) while hashing differently.
+    /// Floats are hashed by Godot's own rules, e.g. all NaN values share one hash, even though NaN never compares equal.
+    ///
+    /// For this reason, `Variant` does not implement [`std::hash::Hash`]: it would not be consistent with `==` across types.
     ///
     /// _Godot equivalent : `@GlobalScope.hash()`_
-    pub fn hash(&self) -> i64 {
-        unsafe { interface_fn!(variant_hash)(self.var_sys()) }
+    pub fn hash(&self) -> u32 {
+        // The GDExtension interface only deals in `i64`, but the engine's own `hash()` function actually returns `uint32_t`.
+        let hash = unsafe { interface_fn!(variant_hash)(self.var_sys()) };
+        hash.try_into().expect("Godot hashes are uint32_t")
+    }
+
+    /// Returns a shallow copy of the variant.
+    ///
+    /// For arrays and dictionaries, a new container is created, but any nested containers (and objects) are still shared with
+    /// the original. For all other types, this is equivalent to [`clone()`][Clone::clone].
+    ///
+    /// To create a deep copy, use [`duplicate_deep()`][Self::duplicate_deep] instead.
+    pub fn duplicate_shallow(&self) -> Variant {
+        self.duplicate(false)
+    }
+
+    /// Returns a deep copy of the variant.
+    ///
+    /// For arrays and dictionaries, all nested arrays and dictionaries are duplicated as well, so mutating the copy never affects
+    /// the original. `Object`-derived elements are still shallow-copied. For all other types, this is equivalent to
+    /// [`clone()`][Clone::clone].
+    ///
+    /// To create a shallow copy, use [`duplicate_shallow()`][Self::duplicate_shallow] instead.
+    pub fn duplicate_deep(&self) -> Variant {
+        self.duplicate(true)
+    }
+
+    fn duplicate(&self, deep: bool) -> Variant {
+        unsafe {
+            Self::new_with_var_uninit(|variant_ptr| {
+                interface_fn!(variant_duplicate)(
+                    self.var_sys(),
+                    variant_ptr,
+                    sys::conv::bool_to_sys(deep),
+                )
+            })
+        }
     }
 
     /// Interpret the `Variant` as `bool`.
@@ -262,12 +839,106 @@ impl Variant {
     /// - empty string
     /// - empty container (array, packed array, dictionary)
     /// - default-constructed other builtins (e.g. zero vector, degenerate plane, zero RID, etc...)
+    ///
+    /// These are the same rules that GDScript applies to conditions like `if value:`. Note that Godot's default `Color` is opaque black,
+    /// so `Color::BLACK` is falsy. To extract a `bool` only from `BOOL` variants, use [`try_to::<bool>()`][Self::try_to] instead.
     pub fn booleanize(&self) -> bool {
         // See Variant::is_zero(), roughly https://github.com/godotengine/godot/blob/master/core/variant/variant.cpp#L859.
 
         unsafe { interface_fn!(variant_booleanize)(self.var_sys()) != 0 }
     }
 
+    /// Encodes the variant into Godot's binary serialization format.
+    ///
+    /// Objects are not encoded; an object variant is stored as its instance ID only. To include objects, use
+    /// [`to_bytes_with_objects()`][Self::to_bytes_with_objects]. Decode with [`from_bytes()`][Self::from_bytes].
+    ///
+    /// _Godot equivalent: `var_to_bytes(variant)`_
+    #[doc(alias = "var_to_bytes")]
+    pub fn to_bytes(&self) -> PackedByteArray {
+        crate::gen::utilities::var_to_bytes(self)
+    }
+
+    /// Encodes the variant into Godot's binary serialization format, including objects.
+    ///
+    /// Decode with [`from_bytes_with_objects()`][Self::from_bytes_with_objects].
+    ///
+    /// # Security
+    /// Decoding objects can instantiate arbitrary classes and scripts, so only use this with trusted data.
+    ///
+    /// _Godot equivalent: `var_to_bytes_with_objects(variant)`_
+    #[doc(alias = "var_to_bytes_with_objects")]
+    pub fn to_bytes_with_objects(&self) -> PackedByteArray {
+        crate::gen::utilities::var_to_bytes_with_objects(self)
+    }
+
+    /// Decodes a variant from Godot's binary serialization format, as produced by [`to_bytes()`][Self::to_bytes].
+    ///
+    /// Returns `Err` if `bytes` is not a valid encoding. Godot additionally prints an error in that case.
+    ///
+    /// _Godot equivalent: `bytes_to_var(bytes)`_
+    #[doc(alias = "bytes_to_var")]
+    pub fn from_bytes(bytes: &PackedByteArray) -> Result<Variant, ConvertError> {
+        let variant = crate::gen::utilities::bytes_to_var(bytes);
+        Self::check_decoded(variant, bytes)
+    }
+
+    /// Decodes a variant from Godot's binary serialization format, including objects.
+    ///
+    /// See [`to_bytes_with_objects()`][Self::to_bytes_with_objects] for security considerations, and [`from_bytes()`][Self::from_bytes]
+    /// for errors.
+    ///
+    /// _Godot equivalent: `bytes_to_var_with_objects(bytes)`_
+    #[doc(alias = "bytes_to_var_with_objects")]
+    pub fn from_bytes_with_objects(bytes: &PackedByteArray) -> Result<Variant, ConvertError> {
+        let variant = crate::gen::utilities::bytes_to_var_with_objects(bytes);
+        Self::check_decoded(variant, bytes)
+    }
+
+    fn check_decoded(variant: Variant, bytes: &PackedByteArray) -> Result<Variant, ConvertError> {
+        // Godot returns nil on decode errors, so a nil result is only legitimate if the header announces one.
+        if variant.is_nil() && !Self::encodes_nil(bytes.as_slice()) {
+            let message = format!("invalid Variant encoding ({} bytes)", bytes.len());
+            return Err(ConvertError::with_kind_value(
+                ErrorKind::Custom(Some(message.into())),
+                bytes.clone(),
+            ));
+        }
+
+        Ok(variant)
+    }
+
+    /// Whether `bytes` is a complete encoding of `null`: either type NIL, or a null object.
+    fn encodes_nil(bytes: &[u8]) -> bool {
+        use crate::obj::EngineEnum;
+
+        // Constants from Godot's `core/io/marshalls.cpp`.
+        const HEADER_TYPE_MASK: u32 = 0xFF;
+        const HEADER_DATA_FLAG_OBJECT_AS_ID: u32 = 1 << 16;
+
+        let Some((header, payload)) = bytes.split_first_chunk::<4>() else {
+            return false;
+        };
+        let header = u32::from_le_bytes(*header);
+        let ty = (header & HEADER_TYPE_MASK) as i32;
+
+        if ty == VariantType::NIL.ord() {
+            true
+        } else if ty == VariantType::OBJECT.ord() {
+            // A null object is stored as instance ID 0 (8 bytes), or as an empty class name (4-byte length) with full objects.
+            let null_len = if header & HEADER_DATA_FLAG_OBJECT_AS_ID != 0 {
+                8
+            } else {
+                4
+            };
+            payload
+                .get(..null_len)
+                .is_some_and(|id_or_len| id_or_len.iter().all(|&b| b == 0))
+        } else {
+            false
+        }
+    }
+
     /// Assuming that this is of type `OBJECT`, checks whether the object is dead.
     ///
     /// Does not check again that the variant has type `OBJECT`.
@@ -498,6 +1169,12 @@ impl Drop for Variant {
 }
 
 // Variant is not Eq because it can contain floats and other types composed of floats.
+/// Compares two variants using Godot's `==` operator.
+///
+/// This follows GDScript semantics: `int` and `float` compare by numeric value (`1 == 1.0`), `String` and `StringName` compare by content,
+/// and containers such as arrays and dictionaries compare their elements. Objects compare by identity.
+///
+/// Unlike GDScript, comparing values of incompatible types (e.g. `int` with `String`) is not an error, but simply returns `false`.
 impl PartialEq for Variant {
     fn eq(&self, other: &Self) -> bool {
         Self::evaluate(self, other, VariantOperator::EQUAL) //.
@@ -506,6 +1183,50 @@ impl PartialEq for Variant {
     }
 }
 
+impl ApproxEq for Variant {
+    /// Compares two variants, tolerating floating-point inaccuracies.
+    ///
+    /// - `int` and `float` values (also mixed) are compared approximately as `f64`.
+    /// - Geometric types with `is_equal_approx()` in Godot (vectors, `Rect2`, `Transform2D`, `Plane`, `Quaternion`, `Aabb`, `Basis`,
+    ///   `Transform3D`, `Color`) delegate to that method, if both variants have the same type.
+    /// - All other values, including containers, are compared with `==`.
+    fn approx_eq(&self, other: &Self) -> bool {
+        use VariantType as T;
+
+        let as_f64 = |variant: &Variant| match variant.get_type() {
+            T::INT => Some(variant.to::<i64>() as f64),
+            T::FLOAT => Some(variant.to::<f64>()),
+            _ => None,
+        };
+
+        if let (Some(lhs), Some(rhs)) = (as_f64(self), as_f64(other)) {
+            return lhs.approx_eq(&rhs);
+        }
+
+        let ty = self.get_type();
+        let has_is_equal_approx = matches!(
+            ty,
+            T::VECTOR2
+                | T::VECTOR3
+                | T::VECTOR4
+                | T::RECT2
+                | T::TRANSFORM2D
+                | T::PLANE
+                | T::QUATERNION
+                | T::AABB
+                | T::BASIS
+                | T::TRANSFORM3D
+                | T::COLOR
+        );
+
+        if has_is_equal_approx && ty == other.get_type() {
+            self.call("is_equal_approx", &[other.clone()]).booleanize()
+        } else {
+            self == other
+        }
+    }
+}
+
 impl fmt::Display for Variant {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = self.stringify();
@@ -530,3 +1251,223 @@ impl fmt::Debug for Variant {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use crate::builtin::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    // Externally tagged by Godot type name, e.g. `{"Vector2":{"x":1.0,"y":2.0}}` in JSON. The names are the ones of VariantType::type_name().
+    //
+    // Dictionaries are stored as a list of key-value pairs, since keys can be arbitrary variants (JSON objects only allow string keys).
+    // Typed arrays are stored like untyped ones; their element type is not preserved.
+    #[derive(Serialize, Deserialize)]
+    enum VariantRepr {
+        Nil,
+        #[serde(rename = "bool")]
+        Bool(bool),
+        #[serde(rename = "int")]
+        Int(i64),
+        #[serde(rename = "float")]
+        Float(f64),
+        String(GString),
+        Vector2(Vector2),
+        Vector2i(Vector2i),
+        Rect2(Rect2),
+        Rect2i(Rect2i),
+        Vector3(Vector3),
+        Vector3i(Vector3i),
+        Transform2D(Transform2D),
+        Vector4(Vector4),
+        Vector4i(Vector4i),
+        Plane(Plane),
+        Quaternion(Quaternion),
+        #[serde(rename = "AABB")]
+        Aabb(Aabb),
+        Basis(Basis),
+        Transform3D(Transform3D),
+        Projection(Projection),
+        Color(Color),
+        StringName(StringName),
+        NodePath(NodePath),
+        #[serde(rename = "RID")]
+        Rid(u64),
+        Dictionary(Vec<(Variant, Variant)>),
+        Array(Vec<Variant>),
+        PackedByteArray(Vec<u8>),
+        PackedInt32Array(Vec<i32>),
+        PackedInt64Array(Vec<i64>),
+        PackedFloat32Array(Vec<f32>),
+        PackedFloat64Array(Vec<f64>),
+        PackedStringArray(Vec<GString>),
+        PackedVector2Array(Vec<Vector2>),
+        PackedVector3Array(Vec<Vector3>),
+        PackedColorArray(Vec<Color>),
+        #[cfg(since_api = "4.3")]
+        PackedVector4Array(Vec<Vector4>),
+    }
+
+    impl VariantRepr {
+        fn from_variant(variant: &Variant) -> Result<Self, String> {
+            use VariantType as T;
+
+            let repr = match variant.get_type() {
+                T::NIL => Self::Nil,
+                T::BOOL => Self::Bool(variant.to()),
+                T::INT => Self::Int(variant.to()),
+                T::FLOAT => Self::Float(variant.to()),
+                T::STRING => Self::String(variant.to()),
+                T::VECTOR2 => Self::Vector2(variant.to()),
+                T::VECTOR2I => Self::Vector2i(variant.to()),
+                T::RECT2 => Self::Rect2(variant.to()),
+                T::RECT2I => Self::Rect2i(variant.to()),
+                T::VECTOR3 => Self::Vector3(variant.to()),
+                T::VECTOR3I => Self::Vector3i(variant.to()),
+                T::TRANSFORM2D => Self::Transform2D(variant.to()),
+                T::VECTOR4 => Self::Vector4(variant.to()),
+                T::VECTOR4I => Self::Vector4i(variant.to()),
+                T::PLANE => Self::Plane(variant.to()),
+                T::QUATERNION => Self::Quaternion(variant.to()),
+                T::AABB => Self::Aabb(variant.to()),
+                T::BASIS => Self::Basis(variant.to()),
+                T::TRANSFORM3D => Self::Transform3D(variant.to()),
+                T::PROJECTION => Self::Projection(variant.to()),
+                T::COLOR => Self::Color(variant.to()),
+                T::STRING_NAME => Self::StringName(variant.to()),
+                T::NODE_PATH => Self::NodePath(variant.to()),
+                T::RID => Self::Rid(variant.to::<Rid>().to_u64()),
+                T::DICTIONARY => {
+                    Self::Dictionary(variant.to::<Dictionary>().iter_shared().collect())
+                }
+                T::ARRAY => {
+                    // SAFETY: type is checked, and elements are only read (typed arrays are accessed as untyped).
+                    let array = unsafe { VariantArray::from_variant_unchecked(variant) };
+                    Self::Array(array.iter_shared().collect())
+                }
+                T::PACKED_BYTE_ARRAY => {
+                    Self::PackedByteArray(variant.to::<PackedByteArray>().to_vec())
+                }
+                T::PACKED_INT32_ARRAY => {
+                    Self::PackedInt32Array(variant.to::<PackedInt32Array>().to_vec())
+                }
+                T::PACKED_INT64_ARRAY => {
+                    Self::PackedInt64Array(variant.to::<PackedInt64Array>().to_vec())
+                }
+                T::PACKED_FLOAT32_ARRAY => {
+                    Self::PackedFloat32Array(variant.to::<PackedFloat32Array>().to_vec())
+                }
+                T::PACKED_FLOAT64_ARRAY => {
+                    Self::PackedFloat64Array(variant.to::<PackedFloat64Array>().to_vec())
+                }
+                T::PACKED_STRING_ARRAY => {
+                    Self::PackedStringArray(variant.to::<PackedStringArray>().to_vec())
+                }
+                T::PACKED_VECTOR2_ARRAY => {
+                    Self::PackedVector2Array(variant.to::<PackedVector2Array>().to_vec())
+                }
+                T::PACKED_VECTOR3_ARRAY => {
+                    Self::PackedVector3Array(variant.to::<PackedVector3Array>().to_vec())
+                }
+                T::PACKED_COLOR_ARRAY => {
+                    Self::PackedColorArray(variant.to::<PackedColorArray>().to_vec())
+                }
+                #[cfg(since_api = "4.3")]
+                T::PACKED_VECTOR4_ARRAY => {
+                    Self::PackedVector4Array(variant.to::<PackedVector4Array>().to_vec())
+                }
+
+                // Objects, callables and signals refer to runtime state and have no meaningful serialized form.
+                other => {
+                    return Err(format!(
+                        "cannot serialize Variant of type {}",
+                        other.type_name()
+                    ))
+                }
+            };
+
+            Ok(repr)
+        }
+
+        fn into_variant(self) -> Variant {
+            match self {
+                Self::Nil => Variant::nil(),
+                Self::Bool(v) => v.to_variant(),
+                Self::Int(v) => v.to_variant(),
+                Self::Float(v) => v.to_variant(),
+                Self::String(v) => v.to_variant(),
+                Self::Vector2(v) => v.to_variant(),
+                Self::Vector2i(v) => v.to_variant(),
+                Self::Rect2(v) => v.to_variant(),
+                Self::Rect2i(v) => v.to_variant(),
+                Self::Vector3(v) => v.to_variant(),
+                Self::Vector3i(v) => v.to_variant(),
+                Self::Transform2D(v) => v.to_variant(),
+                Self::Vector4(v) => v.to_variant(),
+                Self::Vector4i(v) => v.to_variant(),
+                Self::Plane(v) => v.to_variant(),
+                Self::Quaternion(v) => v.to_variant(),
+                Self::Aabb(v) => v.to_variant(),
+                Self::Basis(v) => v.to_variant(),
+                Self::Transform3D(v) => v.to_variant(),
+                Self::Projection(v) => v.to_variant(),
+                Self::Color(v) => v.to_variant(),
+                Self::StringName(v) => v.to_variant(),
+                Self::NodePath(v) => v.to_variant(),
+                Self::Rid(v) => Rid::new(v).to_variant(),
+                Self::Dictionary(entries) => {
+                    let mut dict = Dictionary::new();
+                    for (key, value) in entries {
+                        dict.set(key, value);
+                    }
+                    dict.to_variant()
+                }
+                Self::Array(v) => v.into_iter().collect::<VariantArray>().to_variant(),
+                Self::PackedByteArray(v) => PackedByteArray::from(v).to_variant(),
+                Self::PackedInt32Array(v) => PackedInt32Array::from(v).to_variant(),
+                Self::PackedInt64Array(v) => PackedInt64Array::from(v).to_variant(),
+                Self::PackedFloat32Array(v) => PackedFloat32Array::from(v).to_variant(),
+                Self::PackedFloat64Array(v) => PackedFloat64Array::from(v).to_variant(),
+                Self::PackedStringArray(v) => PackedStringArray::from(v).to_variant(),
+                Self::PackedVector2Array(v) => PackedVector2Array::from(v).to_variant(),
+                Self::PackedVector3Array(v) => PackedVector3Array::from(v).to_variant(),
+                Self::PackedColorArray(v) => PackedColorArray::from(v).to_variant(),
+                #[cfg(since_api = "4.3")]
+                Self::PackedVector4Array(v) => PackedVector4Array::from(v).to_variant(),
+            }
+        }
+    }
+
+    /// Serializes the variant tagged with its Godot type name, e.g. `{"Vector2":{"x":1.0,"y":2.0}}` in JSON.
+    ///
+    /// All types except `Object`, `Callable` and `Signal` are supported; those return an error. Dictionaries are represented as lists of
+    /// key-value pairs. Typed arrays are serialized like untyped ones and lose their element type.
+    ///
+    /// Some formats cannot represent all values losslessly. For example, JSON has no NaN or infinity, so `serde_json` writes non-finite
+    /// floats as `null`, which fails to deserialize. Integers are stored as `i64`, which other JSON consumers may read with less precision.
+    ///
+    /// The serialized representation underlies **no stability guarantees** and may change at any time.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl Serialize for Variant {
+        #[inline]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            VariantRepr::from_variant(self)
+                .map_err(serde::ser::Error::custom)?
+                .serialize(serializer)
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for Variant {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            VariantRepr::deserialize(deserializer).map(VariantRepr::into_variant)
+        }
+    }
+}
@@ -107,13 +107,19 @@ impl Vector2 {
 
     /// Returns the **signed** angle between `self` and the given vector, as radians in `[-π, +π]`.
     ///
+    /// The angle is positive if `to` is clockwise from `self` (in Godot's Y-down 2D coordinate system). If either vector is zero,
+    /// `0.0` is returned.
+    ///
     /// Note that behavior is different from 3D [`Vector3::angle_to()`] which returns the **unsigned** angle.
     #[inline]
     pub fn angle_to(self, to: Self) -> real {
-        self.glam2(&to, |a, b| a.angle_to(b))
+        // Same formula as Godot; unlike an acos-based approach, this is accurate for small angles and well-defined for zero vectors.
+        self.cross(to).atan2(self.dot(to))
     }
 
-    /// Returns the angle to the given vector, in radians.
+    /// Returns the angle of the direction from `self` to the point `to`, in radians in `[-π, +π]`.
+    ///
+    /// This is equivalent to `(to - self).angle()`.
     ///
     /// [Illustration of the returned angle.](https://raw.githubusercontent.com/godotengine/godot-docs/master/img/vector2_angle_to.png)
     #[inline]
@@ -233,6 +239,52 @@ mod test {
         assert_eq!(vector.sign(), Vector2::new(1., 0.));
     }
 
+    #[test]
+    fn angles() {
+        use crate::builtin::real_consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        assert_eq!(Vector2::RIGHT.angle(), 0.0);
+        assert_eq_approx!(Vector2::DOWN.angle(), FRAC_PI_2);
+        assert_eq_approx!(Vector2::LEFT.angle(), PI);
+        assert_eq_approx!(Vector2::UP.angle(), -FRAC_PI_2);
+        assert_eq_approx!(Vector2::new(1.0, -1.0).angle(), -FRAC_PI_4);
+        assert_eq!(Vector2::ZERO.angle(), 0.0);
+
+        // Signed, positive towards +Y.
+        assert_eq_approx!(Vector2::RIGHT.angle_to(Vector2::DOWN), FRAC_PI_2);
+        assert_eq_approx!(Vector2::DOWN.angle_to(Vector2::RIGHT), -FRAC_PI_2);
+        assert_eq_approx!(Vector2::RIGHT.angle_to(Vector2::LEFT), PI);
+        assert_eq!(Vector2::RIGHT.angle_to(Vector2::RIGHT * 3.0), 0.0);
+        assert_eq!(Vector2::ZERO.angle_to(Vector2::RIGHT), 0.0);
+        assert_eq_approx!(Vector2::new(1.0, 0.001).angle_to(Vector2::RIGHT), -0.001);
+
+        let from = Vector2::new(1.0, 1.0);
+        assert_eq_approx!(from.angle_to_point(Vector2::new(1.0, 3.0)), FRAC_PI_2);
+        assert_eq_approx!(from.angle_to_point(Vector2::new(-1.0, 1.0)), PI);
+        assert_eq_approx!(from.angle_to_point(Vector2::ZERO), -3.0 * FRAC_PI_4);
+    }
+
+    #[test]
+    fn from_angle_rotated() {
+        use crate::builtin::real_consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+        assert_eq_approx!(Vector2::from_angle(0.0), Vector2::RIGHT);
+        assert_eq_approx!(Vector2::from_angle(FRAC_PI_2), Vector2::DOWN);
+        assert_eq_approx!(Vector2::from_angle(PI), Vector2::LEFT);
+        assert_eq_approx!(Vector2::from_angle(-FRAC_PI_2), Vector2::UP);
+
+        // angle() wraps into [-π, +π].
+        assert_eq_approx!(Vector2::from_angle(TAU + FRAC_PI_4).angle(), FRAC_PI_4);
+        assert_eq_approx!(Vector2::from_angle(-TAU + 0.5).angle(), 0.5);
+
+        let v = Vector2::new(3.0, 4.0);
+        assert_eq_approx!(v.rotated(FRAC_PI_2), Vector2::new(-4.0, 3.0));
+        assert_eq_approx!(v.rotated(PI), -v);
+        assert_eq_approx!(v.rotated(TAU), v);
+        assert_eq_approx!(v.rotated(0.3).angle(), v.angle() + 0.3);
+        assert_eq_approx!(v.rotated(0.3).length(), v.length());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
@@ -241,4 +293,48 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&vector, expected_json);
     }
+
+    test_vector_operators!(Vector2, real, [1.5, -2.0], [0.5, 4.0], 2.0);
+
+    #[test]
+    fn division_by_zero() {
+        // Follows IEEE 754, like in Godot.
+        let by_zero = Vector2::new(1.0, -1.0) / Vector2::ZERO;
+        assert_eq!(by_zero.x, real::INFINITY);
+        assert_eq!(by_zero.y, -real::INFINITY);
+    }
+
+    #[test]
+    fn iter_sum_product() {
+        let points = vec![
+            Vector2::new(1.0, 2.0),
+            Vector2::new(3.0, -4.0),
+            Vector2::new(5.0, 8.0),
+        ];
+
+        let sum: Vector2 = points.iter().sum();
+        assert_eq!(sum, Vector2::new(9.0, 6.0));
+        assert_eq!(points.iter().copied().sum::<Vector2>(), sum);
+
+        let centroid = sum / points.len() as real;
+        assert_eq_approx!(centroid, Vector2::new(3.0, 2.0));
+
+        let product: Vector2 = points.iter().product();
+        assert_eq!(product, Vector2::new(15.0, -64.0));
+        assert_eq!(points.into_iter().product::<Vector2>(), product);
+
+        // Empty iterators yield the identity element.
+        assert_eq!(
+            std::iter::empty::<Vector2>().sum::<Vector2>(),
+            Vector2::ZERO
+        );
+        assert_eq!(
+            std::iter::empty::<&Vector2>().sum::<Vector2>(),
+            Vector2::ZERO
+        );
+        assert_eq!(
+            std::iter::empty::<Vector2>().product::<Vector2>(),
+            Vector2::ONE
+        );
+    }
 }
@@ -34,6 +34,14 @@ use std::fmt;
 /// - [2D functions](#2d-functions)
 /// - [Trait impls + operators](#trait-implementations)
 ///
+#[doc = integer_vector_arithmetic_docs!()]
+///
+/// # Ordering
+///
+/// Integer vectors implement `Ord`, comparing components lexicographically: first `x`, then `y`. This is the same order as Godot's
+/// `<` and `>` operators, and allows using vectors as keys in ordered collections such as `BTreeMap`. It does not compare lengths;
+/// use [`length_squared()`][Self::length_squared] for that.
+///
 /// # All vector types
 ///
 /// | Dimension | Floating-point                       | Integer                                |
@@ -131,6 +139,36 @@ impl GlamConv for Vector2i {
 mod test {
     use super::*;
 
+    #[test]
+    fn ordering() {
+        let mut coords = vec![
+            Vector2i::new(1, 5),
+            Vector2i::new(0, 9),
+            Vector2i::new(1, -2),
+            Vector2i::new(0, 0),
+        ];
+        coords.sort();
+        assert_eq!(
+            coords,
+            vec![
+                Vector2i::new(0, 0),
+                Vector2i::new(0, 9),
+                Vector2i::new(1, -2),
+                Vector2i::new(1, 5)
+            ]
+        );
+
+        // Lexicographic, not by length.
+        assert!(Vector2i::new(1, 0) > Vector2i::new(0, 100));
+        assert_eq!(
+            Vector2i::new(3, 3).cmp(&Vector2i::new(3, 3)),
+            Ordering::Equal
+        );
+
+        let set: std::collections::BTreeSet<Vector2i> = coords.iter().copied().collect();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), coords);
+    }
+
     #[test]
     fn coord_min_max() {
         let a = Vector2i::new(1, 3);
@@ -167,4 +205,48 @@ mod test {
         assert_eq!(Vector2i::new(15, 15).max_axis(), None);
         assert_eq!(Vector2i::new(15, 15).min_axis(), None);
     }
+
+    test_vector_operators!(Vector2i, i32, [7, -7], [2, 2], 3);
+
+    #[test]
+    fn division_truncates() {
+        // Towards zero, like in Godot (not flooring).
+        let a = Vector2i::new(7, -7);
+        assert_eq!(a / Vector2i::new(2, 2), Vector2i::new(3, -3));
+        assert_eq!(a / 2, Vector2i::new(3, -3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let _ = Vector2i::new(1, 1) / Vector2i::ZERO;
+    }
+
+    #[test]
+    fn clamp_abs_min_max() {
+        let min = Vector2i::new(-2, 0);
+        let max = Vector2i::new(2, 10);
+
+        assert_eq!(Vector2i::new(1, 5).clamp(min, max), Vector2i::new(1, 5));
+        assert_eq!(Vector2i::new(-7, 15).clamp(min, max), Vector2i::new(-2, 10));
+        assert_eq!(Vector2i::new(3, -1).clamp(min, max), Vector2i::new(2, 0));
+
+        assert_eq!(Vector2i::new(-3, 4).abs(), Vector2i::new(3, 4));
+        assert_eq!(
+            Vector2i::new(i32::MAX, -i32::MAX).abs(),
+            Vector2i::new(i32::MAX, i32::MAX)
+        );
+
+        let a = Vector2i::new(-3, 4);
+        let b = Vector2i::new(1, -6);
+        assert_eq!(a.coord_min(b), Vector2i::new(-3, -6));
+        assert_eq!(a.coord_max(b), Vector2i::new(1, 4));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn abs_min_overflow() {
+        let _ = Vector2i::new(i32::MIN, 0).abs();
+    }
 }
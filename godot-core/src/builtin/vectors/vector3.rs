@@ -105,6 +105,44 @@ impl Vector3 {
         inner::InnerVector3::from_outer(self)
     }
 
+    /// Returns the `x` and `y` components as a `Vector2`.
+    ///
+    /// For arbitrary component combinations, see the [`swizzle!`][crate::builtin::swizzle] macro.
+    #[inline]
+    pub const fn xy(self) -> Vector2 {
+        Vector2::new(self.x, self.y)
+    }
+
+    /// Returns the `x` and `z` components as a `Vector2`, e.g. the horizontal projection of a Y-up position.
+    #[inline]
+    pub const fn xz(self) -> Vector2 {
+        Vector2::new(self.x, self.z)
+    }
+
+    /// Returns the `y` and `z` components as a `Vector2`.
+    #[inline]
+    pub const fn yz(self) -> Vector2 {
+        Vector2::new(self.y, self.z)
+    }
+
+    /// Returns the vector with its components reversed: `(z, y, x)`.
+    #[inline]
+    pub const fn zyx(self) -> Self {
+        Self::new(self.z, self.y, self.x)
+    }
+
+    /// Returns the vector with its components rotated left: `(y, z, x)`.
+    #[inline]
+    pub const fn yzx(self) -> Self {
+        Self::new(self.y, self.z, self.x)
+    }
+
+    /// Returns the vector with its components rotated right: `(z, x, y)`.
+    #[inline]
+    pub const fn zxy(self) -> Self {
+        Self::new(self.z, self.x, self.y)
+    }
+
     /// Returns the cross product of this vector and `with`.
     ///
     /// This returns a vector perpendicular to both this and `with`, which would be the normal vector of the plane
@@ -323,6 +361,56 @@ mod test {
         );
     }
 
+    #[test]
+    fn swizzles() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v.xy(), Vector2::new(1.0, 2.0));
+        assert_eq!(v.xz(), Vector2::new(1.0, 3.0));
+        assert_eq!(v.yz(), Vector2::new(2.0, 3.0));
+
+        assert_eq!(v.zyx(), Vector3::new(3.0, 2.0, 1.0));
+        assert_eq!(v.yzx(), Vector3::new(2.0, 3.0, 1.0));
+        assert_eq!(v.zxy(), Vector3::new(3.0, 1.0, 2.0));
+        assert_eq!(v.yzx().zxy(), v);
+
+        const XZ: Vector2 = Vector3::new(4.0, 5.0, 6.0).xz();
+        assert_eq!(XZ, Vector2::new(4.0, 6.0));
+    }
+
+    #[test]
+    fn direction_constants() {
+        // Same as Godot: Y is up, and the camera looks along -Z.
+        assert_eq!(Vector3::UP, Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(Vector3::DOWN, Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(Vector3::LEFT, Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(Vector3::RIGHT, Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(Vector3::FORWARD, Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(Vector3::BACK, Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(Vector3::ZERO, Vector3::new(0.0, 0.0, 0.0));
+        assert_eq!(Vector3::ONE, Vector3::new(1.0, 1.0, 1.0));
+
+        assert_eq!(Vector3::UP, -Vector3::DOWN);
+        assert_eq!(Vector3::FORWARD, -Vector3::BACK);
+        assert_eq!(Vector3::RIGHT.cross(Vector3::UP), Vector3::BACK);
+    }
+
+    #[test]
+    fn direction_distance() {
+        let from = Vector3::new(1.0, 2.0, 3.0);
+        let to = Vector3::new(1.0, 2.0, -1.0);
+
+        assert_eq_approx!(from.direction_to(to), Vector3::FORWARD);
+        assert_eq_approx!(to.direction_to(from), Vector3::BACK);
+        assert_eq_approx!(from.distance_to(to), 4.0);
+        assert_eq_approx!(from.distance_squared_to(to), 16.0);
+        assert_eq!(from.try_direction_to(from), None);
+
+        let diagonal = Vector3::ZERO.direction_to(Vector3::ONE);
+        assert_eq_approx!(diagonal.length(), 1.0);
+        assert_eq_approx!(diagonal.x, diagonal.z);
+    }
+
     #[test]
     fn sign() {
         let vector = Vector3::new(0.2, -0.5, 0.0);
@@ -395,6 +483,62 @@ mod test {
         assert_eq_approx!(vector1.slerp(vector2, 0.5).length(), real!(6.258_311));
     }
 
+    #[test]
+    fn reflect_bounce_slide() {
+        let v = Vector3::new(1.0, -2.0, 3.0);
+
+        // Plane with normal +Y: the Y component is the one perpendicular to the plane.
+        assert_eq!(v.reflect(Vector3::UP), Vector3::new(-1.0, -2.0, -3.0));
+        assert_eq!(v.bounce(Vector3::UP), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(v.slide(Vector3::UP), Vector3::new(1.0, 0.0, 3.0));
+
+        // Orientation of the normal does not matter.
+        assert_eq!(v.reflect(Vector3::DOWN), v.reflect(Vector3::UP));
+        assert_eq!(v.bounce(Vector3::DOWN), v.bounce(Vector3::UP));
+        assert_eq!(v.slide(Vector3::DOWN), v.slide(Vector3::UP));
+
+        // Plane with normal +X.
+        assert_eq!(v.bounce(Vector3::RIGHT), Vector3::new(-1.0, -2.0, 3.0));
+        assert_eq!(v.slide(Vector3::RIGHT), Vector3::new(0.0, -2.0, 3.0));
+
+        // Sliding removes the normal component; bouncing flips it.
+        let n = Vector3::new(1.0, 1.0, 0.0).normalized();
+        assert_eq_approx!(v.slide(n).dot(n), 0.0);
+        assert_eq_approx!(v.bounce(n).dot(n), -v.dot(n));
+        assert_eq_approx!(v.bounce(n).length(), v.length());
+    }
+
+    #[test]
+    #[should_panic]
+    fn reflect_non_normalized() {
+        Vector3::new(1.0, 2.0, 3.0).reflect(Vector3::new(0.0, 2.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn slide_zero_normal() {
+        Vector3::new(1.0, 2.0, 3.0).slide(Vector3::ZERO);
+    }
+
+    #[test]
+    fn project() {
+        let v = Vector3::new(1.0, -2.0, 3.0);
+
+        assert_eq!(
+            v.project(Vector3::new(0.0, 5.0, 0.0)),
+            Vector3::new(0.0, -2.0, 0.0)
+        );
+        assert_eq_approx!(
+            v.project(Vector3::new(1.0, 0.0, 1.0)),
+            Vector3::new(2.0, 0.0, 2.0)
+        );
+        assert_eq!(v.project(v), v);
+
+        // Projecting onto zero vector divides by zero, like in Godot.
+        let onto_zero = v.project(Vector3::ZERO);
+        assert!(onto_zero.x.is_nan() && onto_zero.y.is_nan() && onto_zero.z.is_nan());
+    }
+
     #[test]
     fn iter_sum() {
         let vecs = vec![
@@ -408,6 +552,11 @@ mod test {
 
         assert_eq_approx!(sum, Vector3::new(12.0, 15.0, 18.0));
         assert_eq_approx!(sum_refs, Vector3::new(12.0, 15.0, 18.0));
+
+        assert_eq!(
+            Vec::<Vector3>::new().into_iter().sum::<Vector3>(),
+            Vector3::ZERO
+        );
     }
 
     #[cfg(feature = "serde")]
@@ -418,4 +567,15 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&vector, expected_json);
     }
+
+    test_vector_operators!(Vector3, real, [1.5, -2.0, 4.0], [0.5, 4.0, -1.0], 2.0);
+
+    #[test]
+    fn division_by_zero() {
+        // Follows IEEE 754, like in Godot.
+        let by_zero = Vector3::new(1.0, -1.0, 0.0) / Vector3::ZERO;
+        assert_eq!(by_zero.x, real::INFINITY);
+        assert_eq!(by_zero.y, -real::INFINITY);
+        assert!(by_zero.z.is_nan());
+    }
 }
@@ -34,6 +34,14 @@ use crate::builtin::{inner, real, RVec3, Vector3, Vector3Axis};
 /// - [3D functions](#3d-functions)
 /// - [Trait impls + operators](#trait-implementations)
 ///
+#[doc = integer_vector_arithmetic_docs!()]
+///
+/// # Ordering
+///
+/// Integer vectors implement `Ord`, comparing components lexicographically: first `x`, then `y`, then `z`. This is the same order as Godot's
+/// `<` and `>` operators, and allows using vectors as keys in ordered collections such as `BTreeMap`. It does not compare lengths;
+/// use [`length_squared()`][Self::length_squared] for that.
+///
 /// # All vector types
 ///
 /// | Dimension | Floating-point                       | Integer                                |
@@ -135,6 +143,36 @@ impl GlamConv for Vector3i {
 mod test {
     use super::*;
 
+    #[test]
+    fn ordering() {
+        let mut coords = vec![
+            Vector3i::new(1, 0, 0),
+            Vector3i::new(0, 2, 1),
+            Vector3i::new(0, 2, -1),
+            Vector3i::new(0, -3, 7),
+        ];
+        coords.sort();
+        assert_eq!(
+            coords,
+            vec![
+                Vector3i::new(0, -3, 7),
+                Vector3i::new(0, 2, -1),
+                Vector3i::new(0, 2, 1),
+                Vector3i::new(1, 0, 0)
+            ]
+        );
+
+        // Lexicographic, not by length.
+        assert!(Vector3i::new(1, 0, 0) > Vector3i::new(0, 100, 100));
+        assert_eq!(
+            Vector3i::new(3, 3, 3).cmp(&Vector3i::new(3, 3, 3)),
+            Ordering::Equal
+        );
+
+        let set: std::collections::BTreeSet<Vector3i> = coords.iter().copied().collect();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), coords);
+    }
+
     #[test]
     fn coord_min_max() {
         let a = Vector3i::new(1, 3, 5);
@@ -177,4 +215,49 @@ mod test {
         assert_eq!(Vector3i::new(15, 15, 25).max_axis(), Some(Vector3Axis::Z));
         assert_eq!(Vector3i::new(15, 5, 15).min_axis(), Some(Vector3Axis::Y));
     }
+
+    test_vector_operators!(Vector3i, i32, [7, -7, 9], [2, 2, -4], 3);
+
+    #[test]
+    fn division_truncates() {
+        // Towards zero, like in Godot (not flooring).
+        let a = Vector3i::new(7, -7, 9);
+        assert_eq!(a / Vector3i::new(2, 2, -4), Vector3i::new(3, -3, -2));
+        assert_eq!(a / 2, Vector3i::new(3, -3, 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let _ = Vector3i::new(1, 1, 1) / Vector3i::ZERO;
+    }
+
+    #[test]
+    fn clamp_abs_min_max() {
+        let min = Vector3i::new(-2, 0, -5);
+        let max = Vector3i::new(2, 10, -5);
+
+        assert_eq!(
+            Vector3i::new(1, 5, -5).clamp(min, max),
+            Vector3i::new(1, 5, -5)
+        );
+        assert_eq!(
+            Vector3i::new(-7, 15, 0).clamp(min, max),
+            Vector3i::new(-2, 10, -5)
+        );
+
+        assert_eq!(Vector3i::new(-3, 4, -5).abs(), Vector3i::new(3, 4, 5));
+
+        let a = Vector3i::new(-3, 4, 0);
+        let b = Vector3i::new(1, -6, 0);
+        assert_eq!(a.coord_min(b), Vector3i::new(-3, -6, 0));
+        assert_eq!(a.coord_max(b), Vector3i::new(1, 4, 0));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn abs_min_overflow() {
+        let _ = Vector3i::new(0, i32::MIN, 0).abs();
+    }
 }
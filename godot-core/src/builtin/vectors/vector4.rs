@@ -150,4 +150,48 @@ mod test {
 
         crate::builtin::test_utils::roundtrip(&vector, expected_json);
     }
+
+    test_vector_operators!(
+        Vector4,
+        real,
+        [1.5, -2.0, 4.0, 0.5],
+        [0.5, 4.0, -1.0, 2.0],
+        2.0
+    );
+
+    #[test]
+    fn division_by_zero() {
+        // Follows IEEE 754, like in Godot.
+        let by_zero = Vector4::new(1.0, -1.0, 0.0, 0.0) / Vector4::ZERO;
+        assert_eq!(by_zero.x, real::INFINITY);
+        assert_eq!(by_zero.y, -real::INFINITY);
+        assert!(by_zero.z.is_nan());
+        assert!(by_zero.w.is_nan());
+    }
+
+    #[test]
+    fn iter_sum_product() {
+        let vecs = [
+            Vector4::new(1.0, 2.0, 3.0, 4.0),
+            Vector4::new(-1.0, 0.5, 2.0, 0.0),
+        ];
+
+        assert_eq!(
+            vecs.iter().sum::<Vector4>(),
+            Vector4::new(0.0, 2.5, 5.0, 4.0)
+        );
+        assert_eq!(
+            vecs.into_iter().product::<Vector4>(),
+            Vector4::new(-1.0, 1.0, 6.0, 0.0)
+        );
+
+        assert_eq!(
+            std::iter::empty::<Vector4>().sum::<Vector4>(),
+            Vector4::ZERO
+        );
+        assert_eq!(
+            std::iter::empty::<&Vector4>().product::<Vector4>(),
+            Vector4::ONE
+        );
+    }
 }
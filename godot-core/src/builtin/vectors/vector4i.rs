@@ -33,6 +33,14 @@ use std::fmt;
 /// - [4D functions](#4d-functions)
 /// - [Trait impls + operators](#trait-implementations)
 ///
+#[doc = integer_vector_arithmetic_docs!()]
+///
+/// # Ordering
+///
+/// Integer vectors implement `Ord`, comparing components lexicographically: first `x`, then `y`, then `z`, then `w`. This is the same order as Godot's
+/// `<` and `>` operators, and allows using vectors as keys in ordered collections such as `BTreeMap`. It does not compare lengths;
+/// use [`length_squared()`][Self::length_squared] for that.
+///
 /// # All vector types
 ///
 /// | Dimension | Floating-point                       | Integer                                |
@@ -138,6 +146,36 @@ impl GlamConv for Vector4i {
 mod test {
     use super::*;
 
+    #[test]
+    fn ordering() {
+        let mut coords = vec![
+            Vector4i::new(0, 0, 0, 1),
+            Vector4i::new(0, 0, 1, 0),
+            Vector4i::new(-1, 9, 9, 9),
+            Vector4i::new(0, 0, 0, 0),
+        ];
+        coords.sort();
+        assert_eq!(
+            coords,
+            vec![
+                Vector4i::new(-1, 9, 9, 9),
+                Vector4i::new(0, 0, 0, 0),
+                Vector4i::new(0, 0, 0, 1),
+                Vector4i::new(0, 0, 1, 0)
+            ]
+        );
+
+        // Lexicographic, not by length.
+        assert!(Vector4i::new(1, 0, 0, 0) > Vector4i::new(0, 100, 100, 100));
+        assert_eq!(
+            Vector4i::new(3, 3, 3, 3).cmp(&Vector4i::new(3, 3, 3, 3)),
+            Ordering::Equal
+        );
+
+        let set: std::collections::BTreeSet<Vector4i> = coords.iter().copied().collect();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), coords);
+    }
+
     #[test]
     fn coord_min_max() {
         let a = Vector4i::new(1, 3, 5, 0);
@@ -199,4 +237,20 @@ mod test {
         assert_eq!(prod_refs, expected);
         assert_eq!(prod, expected);
     }
+
+    test_vector_operators!(Vector4i, i32, [7, -7, 9, -1], [2, 2, -4, 3], 3);
+
+    #[test]
+    fn division_truncates() {
+        // Towards zero, like in Godot (not flooring).
+        let a = Vector4i::new(7, -7, 9, -1);
+        assert_eq!(a / Vector4i::new(2, 2, -4, 3), Vector4i::new(3, -3, -2, 0));
+        assert_eq!(a / 2, Vector4i::new(3, -3, 4, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn division_by_zero() {
+        let _ = Vector4i::new(1, 1, 1, 1) / Vector4i::ZERO;
+    }
 }
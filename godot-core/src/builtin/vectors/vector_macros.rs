@@ -169,6 +169,8 @@ macro_rules! impl_iter_vector_reduction {
     ) => {
         impl std::iter::$Operator<Self> for $Vector {
             #[doc = concat!("Element-wise ", stringify!($func), " of all vectors in the iterator.")]
+            ///
+            /// An empty iterator yields the identity element, i.e. `ZERO` for sums and `ONE` for products.
             fn $func<I>(iter: I) -> Self
             where
                 I: Iterator<Item = Self>,
@@ -179,6 +181,8 @@ macro_rules! impl_iter_vector_reduction {
 
         impl<'a> std::iter::$Operator<&'a Self> for $Vector {
             #[doc = concat!("Element-wise ", stringify!($func), " of all vectors in the iterator.")]
+            ///
+            /// An empty iterator yields the identity element, i.e. `ZERO` for sums and `ONE` for products.
             fn $func<I>(iter: I) -> Self
             where
                 I: Iterator<Item = &'a Self>,
@@ -218,6 +222,57 @@ macro_rules! impl_vector_operators {
     }
 }
 
+/// Generates a unit test checking that all operators from `impl_vector_operators!` act component-wise.
+#[cfg(test)]
+macro_rules! test_vector_operators {
+    (
+        // Name of the vector type, for example `Vector2`.
+        $Vector:ty,
+        // Type of each individual component, for example `real`.
+        $Scalar:ty,
+        // Components of the two vector operands, and a scalar operand. None of them may contain zero (used as divisors).
+        $a:expr, $b:expr, $s:expr
+    ) => {
+        #[test]
+        fn operators() {
+            fn componentwise(
+                u: $Vector,
+                v: $Vector,
+                op: fn($Scalar, $Scalar) -> $Scalar,
+            ) -> $Vector {
+                let (u, v) = (u.to_array(), v.to_array());
+                <$Vector>::from_array(std::array::from_fn(|i| op(u[i], v[i])))
+            }
+
+            fn assigned(mut v: $Vector, op: impl FnOnce(&mut $Vector)) -> $Vector {
+                op(&mut v);
+                v
+            }
+
+            let a = <$Vector>::from_array($a);
+            let b = <$Vector>::from_array($b);
+            let s: $Scalar = $s;
+            let splat = <$Vector>::splat(s);
+
+            assert_eq!(-a, componentwise(a, a, |x, _| -x));
+            assert_eq!(a + b, componentwise(a, b, |x, y| x + y));
+            assert_eq!(a - b, componentwise(a, b, |x, y| x - y));
+            assert_eq!(a * b, componentwise(a, b, |x, y| x * y));
+            assert_eq!(a / b, componentwise(a, b, |x, y| x / y));
+            assert_eq!(a * s, a * splat);
+            assert_eq!(s * a, a * s);
+            assert_eq!(a / s, a / splat);
+
+            assert_eq!(assigned(a, |c| *c += b), a + b);
+            assert_eq!(assigned(a, |c| *c -= b), a - b);
+            assert_eq!(assigned(a, |c| *c *= b), a * b);
+            assert_eq!(assigned(a, |c| *c *= s), a * s);
+            assert_eq!(assigned(a, |c| *c /= b), a / b);
+            assert_eq!(assigned(a, |c| *c /= s), a / s);
+        }
+    };
+}
+
 /// Implements `Index` and `IndexMut` for a vector type, using an enum to indicate the desired axis.
 macro_rules! impl_vector_index {
     (
@@ -337,6 +392,14 @@ macro_rules! shared_vector_docs {
     };
 }
 
+macro_rules! integer_vector_arithmetic_docs {
+    () => {
+        "# Arithmetic\n\n\
+         Operators work component-wise. Integer division truncates towards zero, like Godot's `/` operator. Unlike GDScript, which reports \
+         an error on division by zero, dividing by a zero component panics, just like Rust's `i32` division. Overflow behaves like `i32` as well."
+    };
+}
+
 macro_rules! tuple_type {
     ($Scalar:ty; $x:ident, $y:ident) => {
         ($Scalar, $Scalar)
@@ -434,6 +497,9 @@ macro_rules! impl_vector_fns {
 
             /// Returns a new vector with all components in absolute values (i.e. positive or
             /// zero).
+            ///
+            /// For integer vectors, a component equal to the type's minimum value (e.g. [`i32::MIN`]) has no positive counterpart. Like
+            /// [`i32::abs()`], this panics in debug builds and returns the component unchanged in release builds; the latter matches Godot.
             #[inline]
             pub fn abs(self) -> Self {
                 Self::from_glam(self.to_glam().abs())
@@ -1047,9 +1113,12 @@ macro_rules! impl_vector2_vector3_fns {
             }
 
             /// Returns the result of projecting the vector onto the given vector `b`.
+            ///
+            /// Like in Godot, `b` does not need to be normalized. If `b` is zero, the result has NaN components.
             #[inline]
             pub fn project(self, b: Self) -> Self {
-                Self::from_glam(self.to_glam().project_onto(b.to_glam()))
+                // Same formula as Godot; glam's project_onto() would panic for zero `b` in debug builds.
+                b * (self.dot(b) / b.length_squared())
             }
 
             /// Returns the result of reflecting the vector defined by the given direction vector `n`.
@@ -193,6 +193,7 @@ fn gdext_on_level_deinit(level: InitLevel) {
         // SAFETY: this is the last time meta APIs are used.
         unsafe {
             crate::meta::cleanup();
+            crate::builtin::cleanup();
         }
 
         // SAFETY: called after all other logic, so no concurrent access.
@@ -201,6 +201,8 @@ where
 
 // Note: for all string types S, `impl AsArg<S> for &mut String` is not yet provided, but we can add them if needed.
 
+// Rust strings are converted on the fly into an owned GString. `&str` is always valid UTF-8 and passed with explicit length, so
+// no validation is needed; intermediate NUL characters are handled by Godot the same way as in `GString::from(&str)`.
 impl AsArg<GString> for &str {
     fn into_arg<'r>(self) -> CowArg<'r, GString> {
         CowArg::Owned(GString::from(self))
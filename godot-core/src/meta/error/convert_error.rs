@@ -86,6 +86,26 @@ impl ConvertError {
         self.value.as_ref()
     }
 
+    /// Returns the Godot type that the conversion expected, if this error was caused by a type mismatch.
+    ///
+    /// Errors that don't originate from a mismatch between variant types (e.g. out-of-range integers or custom errors) return `None`.
+    pub fn expected_type(&self) -> Option<VariantType> {
+        match &self.kind {
+            ErrorKind::FromVariant(FromVariantError::BadType { expected, .. }) => Some(*expected),
+            _ => None,
+        }
+    }
+
+    /// Returns the Godot type that was actually encountered, if this error was caused by a type mismatch.
+    ///
+    /// See [`expected_type()`][Self::expected_type] for when this returns `None`.
+    pub fn actual_type(&self) -> Option<VariantType> {
+        match &self.kind {
+            ErrorKind::FromVariant(FromVariantError::BadType { actual, .. }) => Some(*actual),
+            _ => None,
+        }
+    }
+
     /// Converts error into generic error type. It is useful to send error across thread.
     /// Do note that some data might get lost during conversion.
     pub fn into_erased(self) -> impl Error + Send + Sync {
@@ -286,7 +306,6 @@ pub(crate) enum FromFfiError {
     U16,
     I32,
     U32,
-    U64,
 }
 
 impl FromFfiError {
@@ -311,7 +330,6 @@ impl fmt::Display for FromFfiError {
             Self::U16 => "u16",
             Self::I32 => "i32",
             Self::U32 => "u32",
-            Self::U64 => "u64",
         };
 
         write!(f, "`{target}` cannot store the given value")
@@ -5,14 +5,18 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{Array, Variant};
+use crate::builtin::{Array, Dictionary, GString, Variant, VariantArray, Vector2i};
 use crate::meta::error::{ConvertError, ErrorKind, FromFfiError, FromVariantError};
 use crate::meta::{
     ArrayElement, ClassName, FromGodot, GodotConvert, GodotNullableFfi, GodotType,
-    PropertyHintInfo, PropertyInfo, ToGodot,
+    PropertyHintInfo, PropertyInfo, TaggedResult, ToGodot,
 };
 use crate::registry::method::MethodParamOrReturnInfo;
 use godot_ffi as sys;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::PathBuf;
 
 // The following ToGodot/FromGodot/Convert impls are auto-generated for each engine type, co-located with their definitions:
 // - enum
@@ -21,6 +25,9 @@ use godot_ffi as sys;
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Option<T>
 
+// Nullable FFI types (objects) are handled by the blanket `GodotType` impl below. Builtin types are not nullable, so their `Option<T>` is
+// passed as `Variant`; see `impl_ffi_variant!`. The conversion traits are generic and work for both.
+
 impl<T> GodotType for Option<T>
 where
     T: GodotType,
@@ -94,11 +101,8 @@ where
 impl<T: ToGodot> ToGodot for Option<T>
 where
     Option<T::Via>: GodotType,
-    for<'v, 'f> T::ToVia<'v>: GodotType<
-        // Associated types need to be nullable.
-        Ffi: GodotNullableFfi,
-        ToFfi<'f>: GodotNullableFfi,
-    >,
+    // Either nullable on the FFI level (objects), or passed as Variant (builtins).
+    for<'v> Option<T::ToVia<'v>>: GodotType,
 {
     type ToVia<'v>
         = Option<T::ToVia<'v>>
@@ -291,6 +295,8 @@ impl_godot_scalar!(
     FromFfiError::U32,
     sys::GDEXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_UINT32
 );
+// Godot's `float` is always 64-bit. Conversion f64 -> f32 rounds to the nearest representable value (IEEE 754 round-to-nearest-even),
+// so precision may be lost. Finite values beyond the f32 range become ±infinity, NaN stays NaN. f32 -> f64 is exact.
 impl_godot_scalar!(
     f32 as f64,
     sys::GDEXTENSION_METHOD_ARGUMENT_METADATA_REAL_IS_FLOAT;
@@ -298,7 +304,11 @@ impl_godot_scalar!(
 );
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
-// u64: manually implemented, to ensure that type is not altered during conversion.
+// u64: manually implemented, since Godot has no unsigned 64-bit integer.
+//
+// Values are stored as `int` by reinterpreting the bits (two's complement), so that round-trips through Godot are lossless.
+// This means that values above `i64::MAX` appear as negative numbers on the GDScript side, e.g. `u64::MAX` becomes `-1`.
+// Conversely, every `int` value -- including negative ones -- is accepted when converting back to `u64`.
 
 impl GodotType for u64 {
     type Ffi = i64;
@@ -313,8 +323,7 @@ impl GodotType for u64 {
     }
 
     fn try_from_ffi(ffi: Self::Ffi) -> Result<Self, ConvertError> {
-        // Ok(ffi as u64)
-        Self::try_from(ffi).map_err(|_rust_err| FromFfiError::U64.into_error(ffi))
+        Ok(ffi as u64)
     }
 
     impl_godot_scalar!(@shared_fns; i64, sys::GDEXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_UINT64);
@@ -330,30 +339,270 @@ impl ToGodot for u64 {
     fn to_godot(&self) -> Self::ToVia<'_> {
         *self
     }
-
-    fn to_variant(&self) -> Variant {
-        // TODO panic doesn't fit the trait's infallibility too well; maybe in the future try_to_godot/try_to_variant() methods are possible.
-        i64::try_from(*self)
-            .map(|v| v.to_variant())
-            .unwrap_or_else(|_| {
-                panic!("to_variant(): u64 value {} is not representable inside Variant, which can only store i64 integers", self)
-            })
-    }
 }
 
 impl FromGodot for u64 {
     fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
         Ok(via)
     }
+}
 
-    fn try_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
-        // Fail for values that are not representable as u64.
-        let value = variant.try_to::<i64>()?;
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// NonZero integers: passed as `int`, through their primitive counterpart (e.g. `NonZeroU32` via `u32`).
+//
+// Zero is rejected when converting from Godot, in addition to the range checks of the primitive type.
+
+macro_rules! impl_nonzero_convert {
+    ($($NonZero:ident => $Int:ty),+ $(,)?) => {
+        $(
+            impl GodotConvert for std::num::$NonZero {
+                type Via = $Int;
+            }
+
+            impl ToGodot for std::num::$NonZero {
+                type ToVia<'v> = $Int;
+
+                fn to_godot(&self) -> Self::ToVia<'_> {
+                    self.get()
+                }
+            }
+
+            impl FromGodot for std::num::$NonZero {
+                fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                    Self::new(via).ok_or_else(|| FromVariantError::BadValue.into_error(via))
+                }
+            }
+        )+
+    };
+}
+
+impl_nonzero_convert!(
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Ranges: passed as `Vector2i(start, end)`.
+//
+// Only `i32` bounds are supported, since `Vector2i` components are 32-bit; an `i64` range could not be converted losslessly.
+// Inverted ranges (start > end) are preserved as-is. Exclusive and inclusive ranges have the same representation, so the end bound's
+// meaning is up to the receiving side.
+
+impl GodotConvert for std::ops::Range<i32> {
+    type Via = Vector2i;
+}
+
+impl ToGodot for std::ops::Range<i32> {
+    type ToVia<'v> = Vector2i;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        Vector2i::new(self.start, self.end)
+    }
+}
+
+impl FromGodot for std::ops::Range<i32> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.x..via.y)
+    }
+}
+
+impl GodotConvert for std::ops::RangeInclusive<i32> {
+    type Via = Vector2i;
+}
+
+impl ToGodot for std::ops::RangeInclusive<i32> {
+    type ToVia<'v> = Vector2i;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        Vector2i::new(*self.start(), *self.end())
+    }
+}
+
+impl FromGodot for std::ops::RangeInclusive<i32> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.x..=via.y)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Duration: passed as `float` seconds, which is what Godot APIs such as `Timer::set_wait_time()` or tweens expect.
+//
+// Sub-nanosecond fractions are rounded to the nearest nanosecond. Very long durations lose precision, since `f64` only represents
+// integers exactly up to 2^53 (nanosecond accuracy is lost beyond ~104 days). Negative, NaN and too large values cannot be converted.
+
+impl GodotConvert for std::time::Duration {
+    type Via = f64;
+}
+
+impl ToGodot for std::time::Duration {
+    type ToVia<'v> = f64;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.as_secs_f64()
+    }
+}
+
+impl FromGodot for std::time::Duration {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Self::try_from_secs_f64(via).map_err(|_| FromVariantError::BadValue.into_error(via))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// char: passed as a `String` with exactly one character.
+//
+// Godot strings are UTF-32, so one `char` corresponds to one Godot character, including astral-plane code points such as emojis.
+// Empty and multi-character strings cannot be converted back to `char`.
+
+impl GodotConvert for char {
+    type Via = GString;
+}
+
+impl ToGodot for char {
+    type ToVia<'v> = GString;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        GString::from([*self].as_slice())
+    }
+}
+
+impl FromGodot for char {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        match via.chars() {
+            &[c] => Ok(c),
+            _ => Err(FromVariantError::BadValue.into_error(via)),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// PathBuf: passed as a `String`, which Godot APIs such as `FileAccess` or `ResourceLoader` accept as paths.
+//
+// Godot uses `/` as separator on all platforms, so on Windows, `\` separators are replaced with `/` when converting to Godot. Godot-specific
+// prefixes like `res://` and `user://` are kept as-is in both directions. Paths that are not valid Unicode are converted lossily
+// (invalid sequences become U+FFFD).
+
+impl GodotConvert for PathBuf {
+    type Via = GString;
+}
+
+impl ToGodot for PathBuf {
+    type ToVia<'v> = GString;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        let path = self.to_string_lossy();
+
+        #[cfg(windows)]
+        let path = path.replace('\\', "/");
 
-        u64::try_from(value).map_err(|_rust_err| {
-            // TODO maybe use better error enumerator
-            FromVariantError::BadValue.into_error(value)
-        })
+        GString::from(&*path)
+    }
+}
+
+impl FromGodot for PathBuf {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(PathBuf::from(via.to_string()))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// IP and socket addresses: passed as a `String`, which Godot APIs such as `PacketPeerUdp::connect_to_host()` or `IP` accept as host.
+//
+// Uses the standard `Display`/`FromStr` formats: IPv6 addresses are written without brackets (`::1`), as Godot expects, while socket
+// addresses put brackets around IPv6 hosts to separate the port (`[::1]:8080`). Godot itself has no string format for socket addresses.
+// Strings that cannot be parsed (including host names such as "localhost") cannot be converted back.
+
+macro_rules! impl_string_parse_convert {
+    ($($(#[$attr:meta])* $T:ty),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl GodotConvert for $T {
+                type Via = GString;
+            }
+
+            $(#[$attr])*
+            impl ToGodot for $T {
+                type ToVia<'v> = GString;
+
+                fn to_godot(&self) -> Self::ToVia<'_> {
+                    GString::from(self.to_string())
+                }
+            }
+
+            $(#[$attr])*
+            impl FromGodot for $T {
+                fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                    via.to_string()
+                        .parse::<$T>()
+                        .map_err(|err| ConvertError::with_error_value(err, via))
+                }
+            }
+        )*
+    };
+}
+
+impl_string_parse_convert!(IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Uuid: passed as a `String` in lowercase hyphenated form, e.g. "67e55044-10b1-426f-9247-bb680e5fe0c8".
+//
+// Converting back also accepts the other formats understood by `Uuid::parse_str()` (simple, braced, URN), in any case.
+// For a compact binary representation, see `PackedByteArray::from_uuid()`.
+
+#[cfg(feature = "uuid")]
+impl_string_parse_convert!(
+    #[cfg_attr(published_docs, doc(cfg(feature = "uuid")))]
+    uuid::Uuid
+);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// TaggedResult<T, E>: passed as a tagged `Dictionary`, either `{ "ok": value }` or `{ "err": error }`.
+//
+// Converting back requires a dictionary with exactly one entry, whose key is one of the two tags.
+
+impl<T: GodotConvert, E: GodotConvert> GodotConvert for TaggedResult<T, E> {
+    type Via = Dictionary;
+}
+
+impl<T: ToGodot, E: ToGodot> ToGodot for TaggedResult<T, E> {
+    type ToVia<'v>
+        = Dictionary
+    where
+        Self: 'v;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        let mut dict = Dictionary::new();
+        match &self.0 {
+            Ok(value) => dict.set("ok", value.to_variant()),
+            Err(error) => dict.set("err", error.to_variant()),
+        }
+        dict
+    }
+}
+
+impl<T: FromGodot, E: FromGodot> FromGodot for TaggedResult<T, E> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        if via.len() == 1 {
+            if let Some(value) = via.get("ok") {
+                return value.try_to::<T>().map(|value| TaggedResult(Ok(value)));
+            }
+            if let Some(error) = via.get("err") {
+                return error.try_to::<E>().map(|error| TaggedResult(Err(error)));
+            }
+        }
+
+        let message =
+            "TaggedResult<T, E> requires a Dictionary with exactly one key, either \"ok\" or \"err\"";
+        Err(ConvertError::with_kind_value(
+            ErrorKind::Custom(Some(message.into())),
+            via,
+        ))
     }
 }
 
@@ -376,6 +625,20 @@ impl<T: ArrayElement> FromGodot for Vec<T> {
     fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
         Ok(via.iter_shared().collect())
     }
+
+    fn try_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        let typed_err = match Array::<T>::try_from_variant(variant) {
+            Ok(array) => return Self::try_from_godot(array),
+            Err(err) => err,
+        };
+
+        // Untyped arrays (e.g. GDScript literals) are converted element by element, reporting the first element that doesn't fit.
+        let Ok(untyped) = VariantArray::try_from_variant(variant) else {
+            return Err(typed_err);
+        };
+
+        untyped.try_to_vec()
+    }
 }
 
 impl<T: ArrayElement, const LEN: usize> GodotConvert for [T; LEN] {
@@ -433,6 +696,127 @@ impl<T: ArrayElement> ToGodot for &[T] {
     }
 }
 
+// HashMap<K, V>: passed as an untyped `Dictionary`.
+//
+// Godot dictionaries preserve insertion order, but `HashMap` does not, so the order of entries after conversion is unspecified.
+// Converting back requires every key and value to be convertible. Distinct Godot keys may become equal after conversion (e.g. two `float`
+// keys that round to the same `f32`); in that case, the entry that comes last in the dictionary wins.
+
+impl<K, V> GodotConvert for HashMap<K, V>
+where
+    K: GodotConvert + Eq + Hash,
+    V: GodotConvert,
+{
+    type Via = Dictionary;
+}
+
+impl<K, V> ToGodot for HashMap<K, V>
+where
+    K: ToGodot + Eq + Hash,
+    V: ToGodot,
+{
+    type ToVia<'v>
+        = Dictionary
+    where
+        Self: 'v;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        let mut dict = Dictionary::new();
+        for (key, value) in self {
+            dict.set(key.to_variant(), value.to_variant());
+        }
+        dict
+    }
+}
+
+impl<K, V> FromGodot for HashMap<K, V>
+where
+    K: FromGodot + Eq + Hash,
+    V: FromGodot,
+{
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        let entry_err = |message: String| {
+            ConvertError::with_kind_value(ErrorKind::Custom(Some(message.into())), via.clone())
+        };
+
+        via.iter_shared()
+            .map(|(key, value)| {
+                let rust_key = key.try_to::<K>().map_err(|err| {
+                    entry_err(format!("Dictionary key {key} cannot be converted: {err}"))
+                })?;
+                let rust_value = value.try_to::<V>().map_err(|err| {
+                    entry_err(format!(
+                        "Dictionary value for key {key} cannot be converted: {err}"
+                    ))
+                })?;
+
+                Ok((rust_key, rust_value))
+            })
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tuples
+
+// Tuples are passed as an untyped `Array` with one element per field, which makes it possible to return multiple values from a #[func].
+// Converting back requires the array to have exactly the tuple's arity, and each element to be convertible to the field's type.
+
+macro_rules! impl_tuple_convert {
+    ($arity:literal; $($T:ident : $index:tt),+) => {
+        impl<$($T: GodotConvert),+> GodotConvert for ($($T,)+) {
+            type Via = VariantArray;
+        }
+
+        impl<$($T: ToGodot),+> ToGodot for ($($T,)+) {
+            type ToVia<'v>
+                = VariantArray
+            where
+                Self: 'v;
+
+            fn to_godot(&self) -> Self::ToVia<'_> {
+                let mut array = VariantArray::new();
+                $(
+                    array.push(&self.$index.to_variant());
+                )+
+                array
+            }
+        }
+
+        impl<$($T: FromGodot),+> FromGodot for ($($T,)+) {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                let via_len = via.len(); // Caching this avoids an FFI call
+                if via_len != $arity {
+                    let message =
+                        format!("Array of length {via_len} cannot be stored in tuple of arity {}", $arity);
+                    return Err(ConvertError::with_kind_value(
+                        ErrorKind::Custom(Some(message.into())),
+                        via,
+                    ));
+                }
+
+                let tuple = ($(
+                    via.at($index).try_to::<$T>().map_err(|err| {
+                        let message = format!("Tuple element at index {} cannot be converted: {err}", $index);
+                        ConvertError::with_kind_value(ErrorKind::Custom(Some(message.into())), via.clone())
+                    })?,
+                )+);
+
+                Ok(tuple)
+            }
+        }
+    };
+}
+
+impl_tuple_convert!(1; A: 0);
+impl_tuple_convert!(2; A: 0, B: 1);
+impl_tuple_convert!(3; A: 0, B: 1, C: 2);
+impl_tuple_convert!(4; A: 0, B: 1, C: 2, D: 3);
+impl_tuple_convert!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_tuple_convert!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_tuple_convert!(7; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_tuple_convert!(8; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Raw pointers
 
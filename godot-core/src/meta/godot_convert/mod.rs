@@ -7,10 +7,12 @@
 
 mod impls;
 
-use crate::builtin::Variant;
-use crate::meta::error::ConvertError;
+use crate::builtin::{Variant, VariantType};
+use crate::meta::error::{ConvertError, FromVariantError};
 use crate::meta::traits::GodotFfiVariant;
 use crate::meta::GodotType;
+use godot_ffi as sys;
+use sys::{interface_fn, GodotFfi};
 
 /// Indicates that a type can be passed to/from Godot, either directly or through an intermediate "via" type.
 ///
@@ -64,7 +66,7 @@ pub trait ToGodot: Sized + GodotConvert {
     fn to_godot(&self) -> Self::ToVia<'_>;
 
     /// Converts this type to a [Variant].
-    // Exception safety: must not panic apart from exceptional circumstances (Nov 2024: only u64).
+    // Exception safety: must not panic apart from exceptional circumstances.
     // This has invariant implications, e.g. in Array::resize().
     fn to_variant(&self) -> Variant {
         self.to_godot().to_ffi().ffi_to_variant()
@@ -120,6 +122,33 @@ pub trait FromGodot: Sized + GodotConvert {
     }
 }
 
+/// Opt-in conversion of [`Result<T, E>`] to/from a tagged [`Dictionary`][crate::builtin::Dictionary].
+///
+/// `Ok(value)` is passed to Godot as `{ "ok": value }`, and `Err(error)` as `{ "err": error }`. This keeps the error channel explicit
+/// in glue code, e.g. when returning a result from a `#[func]` to GDScript. Converting back requires a dictionary with exactly one
+/// entry, whose key is one of the two tags.
+///
+/// `Result` itself does not implement the conversion traits, as there is no canonical Godot representation for it.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::meta::TaggedResult;
+///
+/// let result: Result<i64, GString> = Ok(42);
+/// let variant = TaggedResult(result).to_variant();
+/// assert_eq!(variant.to::<Dictionary>(), dict! { "ok": 42 });
+///
+/// let back: Result<i64, GString> = variant.to::<TaggedResult<i64, GString>>().0;
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TaggedResult<T, E>(pub Result<T, E>);
+
+impl<T, E> From<Result<T, E>> for TaggedResult<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        Self(result)
+    }
+}
+
 pub(crate) fn into_ffi_variant<T: ToGodot>(value: &T) -> Variant {
     let via = value.to_godot();
     let ffi = via.to_ffi();
@@ -133,6 +162,61 @@ pub(crate) fn try_from_ffi<T: FromGodot>(
     T::try_from_godot(via)
 }
 
+/// Converts a variant to `T`, allowing the same implicit type coercions as Godot (e.g. `INT` -> `FLOAT`, `STRING` -> `INT`).
+///
+/// If source and target type are identical, this is equivalent to [`FromGodot::try_from_variant()`].
+pub(crate) fn try_from_variant_relaxed<T: FromGodot>(variant: &Variant) -> Result<T, ConvertError> {
+    let from_type = variant.get_type();
+    let to_type = <<T::Via as GodotType>::Ffi as GodotFfi>::VARIANT_TYPE;
+
+    // Same type: use strict conversion, which is cheaper and keeps both paths consistent.
+    // NIL as target means `Variant` or `()`, for which coercions make no sense.
+    if from_type == to_type || to_type == VariantType::NIL {
+        return T::try_from_variant(variant);
+    }
+
+    // SAFETY: plain query on two valid type enumerators; no pointers involved.
+    let is_convertible =
+        unsafe { interface_fn!(variant_can_convert)(from_type.sys(), to_type.sys()) };
+
+    if is_convertible != sys::conv::SYS_TRUE {
+        return Err(FromVariantError::BadType {
+            expected: to_type,
+            actual: from_type,
+        }
+        .into_error(variant.clone()));
+    }
+
+    // Godot casts FLOAT -> INT in C++, which is undefined for NaN, infinity and values outside the i64 range.
+    // Reject those instead of returning a platform-dependent result.
+    if from_type == VariantType::FLOAT && to_type == VariantType::INT {
+        let value = variant.to::<f64>();
+
+        // i64::MAX as f64 rounds up to 2^63, which itself is not representable -> exclusive upper bound.
+        if !(value >= i64::MIN as f64 && value < i64::MAX as f64) {
+            return Err(FromVariantError::BadValue.into_error(value));
+        }
+    }
+
+    // SAFETY: to_type is a valid, non-NIL type enumerator (NIL returned early above).
+    let converter = unsafe { interface_fn!(get_variant_to_type_constructor)(to_type.sys()) };
+    let converter = converter
+        .unwrap_or_else(|| panic!("no Variant converter for {from_type:?} -> {to_type:?}"));
+
+    // The converter constructs the target through Godot's `Variant::operator T()`, which performs the coercion.
+    // SAFETY: `variant` is a live Variant borrowed for the whole call; the converter only reads it, despite the mut pointer.
+    // `self_ptr` points to uninitialized storage of the target's FFI type, matching `to_type`, which the converter always
+    // initializes: variant_can_convert() confirmed the coercion above, and for unsupported values Godot writes a default value.
+    let ffi = unsafe {
+        <<T::Via as GodotType>::Ffi as GodotFfi>::new_with_uninit(|self_ptr| {
+            converter(self_ptr, sys::SysPtr::force_mut(variant.var_sys()));
+        })
+    };
+
+    // Can still fail for narrower types, e.g. INT -> i8.
+    try_from_ffi(ffi)
+}
+
 #[macro_export]
 macro_rules! impl_godot_as_self {
     ($T:ty) => {
@@ -57,13 +57,14 @@ pub mod error;
 
 pub use args::*;
 pub use class_name::ClassName;
-pub use godot_convert::{FromGodot, GodotConvert, ToGodot};
+pub use godot_convert::{FromGodot, GodotConvert, TaggedResult, ToGodot};
 pub use traits::{ArrayElement, GodotType, PackedArrayElement};
 
 #[cfg(since_api = "4.2")]
 pub use crate::registry::signal::variadic::ParamTuple;
 
 pub(crate) use array_type_info::ArrayTypeInfo;
+pub(crate) use godot_convert::try_from_variant_relaxed;
 pub(crate) use traits::{
     element_godot_type_name, element_variant_type, GodotFfiVariant, GodotNullableFfi,
 };
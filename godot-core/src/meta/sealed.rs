@@ -10,7 +10,7 @@
 // To ensure the user does not implement `GodotType` for their own types.
 use crate::builtin::*;
 use crate::meta;
-use crate::meta::traits::{ArrayElement, GodotNullableFfi, GodotType};
+use crate::meta::traits::ArrayElement;
 use crate::obj::{DynGd, Gd, GodotClass, RawGd};
 
 pub trait Sealed {}
@@ -66,9 +66,4 @@ impl<T: GodotClass> Sealed for Gd<T> {}
 impl<T: GodotClass> Sealed for RawGd<T> {}
 impl<T: GodotClass, D: ?Sized> Sealed for DynGd<T, D> {}
 impl<T: GodotClass> Sealed for meta::ObjectArg<T> {}
-impl<T> Sealed for Option<T>
-where
-    T: GodotType,
-    T::Ffi: GodotNullableFfi,
-{
-}
+impl<T: Sealed> Sealed for Option<T> {}
@@ -140,7 +140,8 @@ pub trait GodotType: GodotConvert<Via = Self> + sealed::Sealed + Sized + 'static
 ///
 /// Notable differences are:
 /// - Only `VariantArray`, not `Array<T>` is allowed (typed arrays cannot be nested).
-/// - `Option` is only supported for `Option<Gd<T>>`, but not e.g. `Option<i32>`.
+/// - `Option` is supported for `Option<Gd<T>>` and builtin types such as `Option<GString>`, but not for scalars like `Option<i32>`.
+///   For builtin types, `Array<Option<T>>` is an untyped array on the Godot side, since Godot's typed arrays cannot hold null.
 ///
 /// # Integer and float types
 /// `u8`, `i8`, `u16`, `i16`, `u32`, `i32` and `f32` are supported by this trait, however they don't have their own array type in Godot.
@@ -153,7 +154,8 @@ pub trait GodotType: GodotConvert<Via = Self> + sealed::Sealed + Sized + 'static
 /// best-effort checks to detect such errors, however they are expensive and not bullet-proof. If you need very rigid type safety, stick to
 /// `i64` and `f64`. The other types however can be extremely convenient and work well, as long as you are aware of the limitations.
 ///
-/// `u64` is entirely unsupported since it cannot be safely stored inside a `Variant`.
+/// `u64` is not supported as an element type. While it can be passed to Godot by reinterpreting its bits as `i64`, the resulting
+/// `Array[int]` would contain negative numbers for large values, which is surprising for a typed array.
 ///
 /// Also, keep in mind that Godot uses `Variant` for each element. If performance matters and you have small element types such as `u8`,
 /// consider using packed arrays (e.g. `PackedByteArray`) instead.
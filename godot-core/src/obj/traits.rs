@@ -207,9 +207,20 @@ pub trait EngineBitfield: Copy {
     }
 
     // TODO consolidate API: named methods vs. | & ! etc.
+    /// Returns `true` if **any** of the bits in `flag` are also set in `self`.
+    ///
+    /// See also [`contains()`][Self::contains], which requires all bits to be set.
     fn is_set(self, flag: Self) -> bool {
         self.ord() & flag.ord() != 0
     }
+
+    /// Returns `true` if **all** of the bits in `flags` are also set in `self`.
+    ///
+    /// Combine flags with `|` and intersect them with `&`. Bits without a named constant (e.g. from newer Godot versions) are
+    /// preserved by all these operations, as well as by conversions from `Variant`.
+    fn contains(self, flags: Self) -> bool {
+        self.ord() & flags.ord() == flags.ord()
+    }
 }
 
 /// Trait for enums that can be used as indices in arrays.
@@ -15,7 +15,7 @@ use std::task::{Context, Poll, Waker};
 use crate::builtin::{Callable, RustCallable, Signal, Variant};
 use crate::classes::object::ConnectFlags;
 use crate::meta::ParamTuple;
-use crate::obj::{EngineBitfield, WithBaseField};
+use crate::obj::WithBaseField;
 use crate::registry::signal::TypedSignal;
 
 /// The panicking counter part to the [`FallibleSignalFuture`].
@@ -181,9 +181,9 @@ impl<R: ParamTuple + Sync + Send> FallibleSignalFuture<R> {
         // The callable currently requires that the return value is Sync + Send.
         let callable = SignalFutureResolver::new(data.clone());
 
-        signal.connect(
+        signal.connect_flags(
             &Callable::from_custom(callable.clone()),
-            ConnectFlags::ONE_SHOT.ord() as i64,
+            ConnectFlags::ONE_SHOT,
         );
 
         Self {
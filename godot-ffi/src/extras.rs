@@ -155,6 +155,77 @@ pub fn panic_call_error(
     panic!("Function call failed:  {function_name} -- {reason}.");
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Variant type names
+
+impl VariantType {
+    /// Name of the type as used by Godot, e.g. `"Vector2"`, `"PackedByteArray"` or `"Nil"`.
+    ///
+    /// This is the same string that GDScript's `type_string()` returns. Some names differ from their Rust counterparts: `"String"`
+    /// (`GString`), `"AABB"` (`Aabb`) and `"RID"` (`Rid`).
+    ///
+    /// Returns the empty string for unknown types (including `MAX`).
+    pub fn type_name(self) -> &'static str {
+        match self {
+            Self::NIL => "Nil",
+            Self::BOOL => "bool",
+            Self::INT => "int",
+            Self::FLOAT => "float",
+            Self::STRING => "String",
+            Self::VECTOR2 => "Vector2",
+            Self::VECTOR2I => "Vector2i",
+            Self::RECT2 => "Rect2",
+            Self::RECT2I => "Rect2i",
+            Self::VECTOR3 => "Vector3",
+            Self::VECTOR3I => "Vector3i",
+            Self::TRANSFORM2D => "Transform2D",
+            Self::VECTOR4 => "Vector4",
+            Self::VECTOR4I => "Vector4i",
+            Self::PLANE => "Plane",
+            Self::QUATERNION => "Quaternion",
+            Self::AABB => "AABB",
+            Self::BASIS => "Basis",
+            Self::TRANSFORM3D => "Transform3D",
+            Self::PROJECTION => "Projection",
+            Self::COLOR => "Color",
+            Self::STRING_NAME => "StringName",
+            Self::NODE_PATH => "NodePath",
+            Self::RID => "RID",
+            Self::OBJECT => "Object",
+            Self::CALLABLE => "Callable",
+            Self::SIGNAL => "Signal",
+            Self::DICTIONARY => "Dictionary",
+            Self::ARRAY => "Array",
+            Self::PACKED_BYTE_ARRAY => "PackedByteArray",
+            Self::PACKED_INT32_ARRAY => "PackedInt32Array",
+            Self::PACKED_INT64_ARRAY => "PackedInt64Array",
+            Self::PACKED_FLOAT32_ARRAY => "PackedFloat32Array",
+            Self::PACKED_FLOAT64_ARRAY => "PackedFloat64Array",
+            Self::PACKED_STRING_ARRAY => "PackedStringArray",
+            Self::PACKED_VECTOR2_ARRAY => "PackedVector2Array",
+            Self::PACKED_VECTOR3_ARRAY => "PackedVector3Array",
+            Self::PACKED_COLOR_ARRAY => "PackedColorArray",
+            #[cfg(since_api = "4.3")]
+            Self::PACKED_VECTOR4_ARRAY => "PackedVector4Array",
+            _ => "",
+        }
+    }
+
+    /// Parses a Godot type name, as returned by [`type_name()`][Self::type_name], back into a `VariantType`.
+    ///
+    /// Names must be spelled as in Godot, so `"String"`, `"AABB"` and `"RID"` are recognized, but not `"GString"`, `"Aabb"` or `"Rid"`.
+    /// Returns `None` for unknown names.
+    pub fn from_godot_name(name: &str) -> Option<Self> {
+        if name.is_empty() {
+            return None;
+        }
+
+        (0..Self::MAX.ord)
+            .map(|ord| Self { ord })
+            .find(|variant_type| variant_type.type_name() == name)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Lazy method table key types
 // Could reuse them in normal load functions, but less code when passing separate parameters -> faster parsing.
@@ -133,6 +133,16 @@
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!
+//! * **`bytemuck`**
+//!
+//!   Enables conversions between slices of [bytemuck](https://docs.rs/bytemuck) `Pod` types and `PackedByteArray`, see
+//!   `PackedByteArray::from_pod_slice()` and `to_pod_vec()`.
+//!
+//! * **`uuid`**
+//!
+//!   Implements `ToGodot`/`FromGodot` for [`uuid::Uuid`](https://docs.rs/uuid), passed as a hyphenated `String`.
+//!   A compact 16-byte form is available through `PackedByteArray::from_uuid()` and `to_uuid()`.
+//!
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/godot-rust/assets/master/gdext/ferris.svg"
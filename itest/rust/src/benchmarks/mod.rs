@@ -10,7 +10,7 @@
 use std::hint::black_box;
 
 use godot::builtin::inner::InnerRect2i;
-use godot::builtin::{GString, PackedInt32Array, Rect2i, StringName, Vector2i};
+use godot::builtin::{GString, PackedByteArray, PackedInt32Array, Rect2i, StringName, Vector2i};
 use godot::classes::{Node3D, Os, RefCounted};
 use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
 use godot::register::GodotClass;
@@ -29,6 +29,24 @@ fn builtin_stringname_ctor() -> StringName {
     StringName::from("some test string")
 }
 
+#[bench]
+fn builtin_stringname_intern() -> StringName {
+    StringName::intern("some test string")
+}
+
+#[bench]
+fn builtin_stringname_to_string() -> String {
+    let name = black_box(StringName::from("some test string"));
+    name.to_string()
+}
+
+#[bench]
+fn builtin_stringname_to_string_via_gstring() -> String {
+    // Naive path for comparison: explicit GString, then UTF-8 conversion by Godot.
+    let name = black_box(StringName::from("some test string"));
+    String::from(&GString::from(&name))
+}
+
 #[bench]
 fn builtin_rust_call() -> bool {
     let point = black_box(Vector2i::new(50, 60));
@@ -93,6 +111,23 @@ fn utilities_ffi_call() -> f64 {
     godot::global::pow(base, exponent)
 }
 
+#[bench(repeat = 25)]
+fn packed_array_from_slice_bulk() -> PackedByteArray {
+    // 64 KiB in one copy; compare with packed_array_push_bytes.
+    let bytes = black_box([0xAB_u8; 64 * 1024]);
+    PackedByteArray::from(bytes.as_slice())
+}
+
+#[bench(repeat = 25)]
+fn packed_array_push_bytes() -> PackedByteArray {
+    let bytes = black_box([0xAB_u8; 64 * 1024]);
+    let mut array = PackedByteArray::new();
+    for byte in bytes {
+        array.push(byte);
+    }
+    array
+}
+
 #[bench(repeat = 25)]
 fn packed_array_from_iter_known_size() -> PackedInt32Array {
     // Create an iterator whose `size_hint()` returns `(len, Some(len))`.
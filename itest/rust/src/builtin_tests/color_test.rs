@@ -53,12 +53,37 @@ fn color_from_u64() {
 
 #[itest]
 fn color_from_html() {
-    assert_eq!(
-        Color::from_string("#abcd"),
-        Some(Color::from_rgba8(0xaa, 0xbb, 0xcc, 0xdd))
-    );
-    assert_eq!(Color::from_string("#abcde"), None);
-    assert_eq!(Color::from_string("#abcg"), None);
+    let rgba = Color::from_rgba8(0xaa, 0xbb, 0xcc, 0xdd);
+    let rgb = Color::from_rgba8(0xaa, 0xbb, 0xcc, 0xff);
+
+    // 3/4/6/8 digits, with or without '#', case-insensitive.
+    assert_eq!(Color::from_html("#abc"), Some(rgb));
+    assert_eq!(Color::from_html("ABC"), Some(rgb));
+    assert_eq!(Color::from_html("#abcd"), Some(rgba));
+    assert_eq!(Color::from_html("aBcD"), Some(rgba));
+    assert_eq!(Color::from_html("#aabbcc"), Some(rgb));
+    assert_eq!(Color::from_html("AABBCC"), Some(rgb));
+    assert_eq!(Color::from_html("#aabbccdd"), Some(rgba));
+    assert_eq!(Color::from_html("AaBbCcDd"), Some(rgba));
+
+    // Malformed input.
+    assert_eq!(Color::from_html("#abcde"), None);
+    assert_eq!(Color::from_html("#abcg"), None);
+    assert_eq!(Color::from_html("#aabbccddee"), None);
+    assert_eq!(Color::from_html(""), None);
+    assert_eq!(Color::from_html("white"), None); // Named colors are only accepted by from_string().
+}
+
+#[itest]
+fn color_to_html() {
+    let color = Color::from_rgba8(0x12, 0x34, 0xab, 0xcd);
+    assert_eq!(color.to_html(), "1234abcd".into());
+    assert_eq!(color.to_html_without_alpha(), "1234ab".into());
+
+    // 8-digit output round-trips losslessly for 8-bit colors.
+    for color in [color, Color::TRANSPARENT_BLACK, Color::WHITE] {
+        assert_eq!(Color::from_html(&color.to_html()), Some(color));
+    }
 }
 
 #[itest]
@@ -77,6 +102,64 @@ fn color_from_string() {
     assert_eq!(Color::from_string("octarine"), None); // Sorry, Rincewind.
 }
 
+#[itest]
+fn color_named_constants() {
+    // Exact values documented by Godot.
+    assert_eq!(Color::RED, Color::from_rgba(1.0, 0.0, 0.0, 1.0));
+    assert_eq!(Color::GREEN, Color::from_rgba(0.0, 1.0, 0.0, 1.0));
+    assert_eq!(Color::BLUE, Color::from_rgba(0.0, 0.0, 1.0, 1.0));
+    assert_eq!(Color::WHITE, Color::from_rgba(1.0, 1.0, 1.0, 1.0));
+    assert_eq!(Color::BLACK, Color::from_rgba(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(
+        Color::TRANSPARENT_WHITE,
+        Color::from_rgba(1.0, 1.0, 1.0, 0.0)
+    );
+
+    // Compare against Godot's own table of named colors.
+    let named = [
+        ("RED", Color::RED),
+        ("GREEN", Color::GREEN),
+        ("BLUE", Color::BLUE),
+        ("WHITE", Color::WHITE),
+        ("BLACK", Color::BLACK),
+        ("TRANSPARENT", Color::TRANSPARENT_WHITE),
+        ("CORNFLOWER_BLUE", Color::CORNFLOWER_BLUE),
+        ("REBECCA_PURPLE", Color::REBECCA_PURPLE),
+    ];
+
+    for (name, color) in named {
+        let godot_color = Color::from_string(name)
+            .unwrap_or_else(|| panic!("Godot should know named color {name}"));
+
+        assert_eq_approx!(godot_color, color, "named color {name}");
+    }
+}
+
+#[itest]
+fn color_from_str() {
+    assert_eq!("red".parse::<Color>().unwrap(), Color::RED);
+    assert_eq_approx!("lawn-green".parse::<Color>().unwrap(), Color::LAWN_GREEN);
+    assert_eq!(
+        "#ff000080".parse::<Color>().unwrap(),
+        Color::from_rgba8(0xff, 0, 0, 0x80)
+    );
+    assert_eq!(
+        "00ff00".parse::<Color>().unwrap(),
+        Color::from_rgba8(0, 0xff, 0, 0xff)
+    );
+
+    for invalid in ["", "#", "#12", "#abcde", "#gggggg", "octarine"] {
+        let err = invalid
+            .parse::<Color>()
+            .expect_err("invalid color must not parse");
+        assert!(err.to_string().contains("HTML color code"), "{err}");
+    }
+
+    // Round-trip through HTML notation.
+    let color = Color::from_rgba8(0x12, 0x34, 0x56, 0x78);
+    assert_eq!(color.to_html().to_string().parse::<Color>().unwrap(), color);
+}
+
 #[itest]
 fn color_get_set_u8() {
     let mut c = Color::default();
@@ -97,6 +180,84 @@ fn color_blend() {
     );
 }
 
+#[itest]
+fn color_blend_alpha() {
+    // Half-transparent blue over opaque red.
+    let base = Color::from_rgba(1.0, 0.0, 0.0, 1.0);
+    let over = Color::from_rgba(0.0, 0.0, 1.0, 0.5);
+    assert_eq_approx!(base.blend(over), Color::from_rgba(0.5, 0.0, 0.5, 1.0));
+
+    // Opaque color on top replaces; fully transparent color on top is a no-op.
+    assert_eq_approx!(base.blend(Color::WHITE), Color::WHITE);
+    assert_eq_approx!(base.blend(over.with_alpha(0.0)), base);
+
+    // Both transparent: result is transparent black.
+    let transparent = Color::from_rgba(0.3, 0.6, 0.9, 0.0);
+    assert_eq!(
+        transparent.blend(transparent),
+        Color::from_rgba(0.0, 0.0, 0.0, 0.0)
+    );
+}
+
+#[itest]
+fn color_lerp() {
+    let from = Color::from_rgba(0.0, 0.0, 0.0, 0.0);
+    let to = Color::from_rgba(1.0, 0.5, 0.2, 1.0);
+
+    assert_eq_approx!(from.lerp(to, 0.0), from);
+    assert_eq_approx!(from.lerp(to, 1.0), to);
+    assert_eq_approx!(from.lerp(to, 0.5), Color::from_rgba(0.5, 0.25, 0.1, 0.5));
+
+    // Weight is not clamped.
+    assert_eq_approx!(from.lerp(to, 2.0), Color::from_rgba(2.0, 1.0, 0.4, 2.0));
+    assert_eq_approx!(
+        from.lerp(to, -1.0),
+        Color::from_rgba(-1.0, -0.5, -0.2, -1.0)
+    );
+}
+
+#[itest]
+fn color_darkened_lightened() {
+    let color = Color::from_rgba(0.8, 0.4, 0.2, 0.5);
+
+    assert_eq_approx!(color.darkened(0.0), color);
+    assert_eq_approx!(color.darkened(0.5), Color::from_rgba(0.4, 0.2, 0.1, 0.5));
+    assert_eq_approx!(color.darkened(1.0), Color::BLACK.with_alpha(0.5));
+
+    assert_eq_approx!(color.lightened(0.0), color);
+    assert_eq_approx!(color.lightened(0.5), Color::from_rgba(0.9, 0.7, 0.6, 0.5));
+    assert_eq_approx!(color.lightened(1.0), Color::WHITE.with_alpha(0.5));
+
+    // Amount is not clamped.
+    assert_eq_approx!(color.darkened(-1.0), Color::from_rgba(1.6, 0.8, 0.4, 0.5));
+    assert_eq_approx!(color.lightened(2.0), Color::from_rgba(1.2, 1.6, 1.8, 0.5));
+}
+
+#[itest]
+fn color_srgb_linear() {
+    // Reference values from the piecewise sRGB transfer function, as implemented in Godot's core/math/color.cpp.
+    let srgb = Color::from_rgba(0.0, 0.5, 1.0, 0.5);
+    let linear = Color::from_rgba(0.0, 0.214041, 1.0, 0.5);
+    assert_eq_approx!(srgb.srgb_to_linear(), linear);
+    assert_eq_approx!(
+        Color::from_rgba(0.0, 0.5, 1.0, 0.25).linear_to_srgb(),
+        Color::from_rgba(0.0, 0.735357, 1.0, 0.25)
+    );
+
+    // Linear segment near black, which a naive gamma curve would not reproduce.
+    assert_eq_approx!(
+        Color::from_rgb(0.02, 0.02, 0.02).srgb_to_linear(),
+        Color::from_rgb(0.001548, 0.001548, 0.001548)
+    );
+    assert_eq_approx!(
+        Color::from_rgb(0.002, 0.002, 0.002).linear_to_srgb(),
+        Color::from_rgb(0.02584, 0.02584, 0.02584)
+    );
+
+    // Roundtrip.
+    assert_eq_approx!(srgb.srgb_to_linear().linear_to_srgb(), srgb);
+}
+
 #[itest]
 fn color_to_u32() {
     let c = Color::from_html("#01020304").unwrap();
@@ -147,6 +308,44 @@ fn color_from_color_hsv() {
     }
 }
 
+#[itest]
+fn color_from_hsva_accessors() {
+    for (h, s, v) in COLOR_HSV_CASES_HSV {
+        let c = Color::from_hsva(h as f64, s as f64, v as f64, 0.5);
+
+        // Hue 1.0 wraps around to 0.0.
+        assert_eq_approx!(c.h(), h.fract(), "h: {h}, s: {s}, v: {v}");
+        assert_eq_approx!(c.s(), s, "h: {h}, s: {s}, v: {v}");
+        assert_eq_approx!(c.v(), v, "h: {h}, s: {s}, v: {v}");
+        assert_eq!(c.a, 0.5);
+
+        // Consistent with the Rust-side HSV conversion.
+        assert_eq_approx!(
+            c.with_alpha(1.0),
+            Color::from_hsv(h as f64, s as f64, v as f64)
+        );
+    }
+
+    // Hue wraps in both directions.
+    let base = Color::from_hsva(0.25, 0.8, 0.6, 1.0);
+    assert_eq_approx!(Color::from_hsva(1.25, 0.8, 0.6, 1.0), base);
+    assert_eq_approx!(Color::from_hsva(-0.75, 0.8, 0.6, 1.0), base);
+    assert_eq_approx!(Color::from_hsva(-0.25, 0.8, 0.6, 1.0).h(), 0.75);
+
+    // Without saturation, the hue is lost.
+    let gray = Color::from_hsva(0.6, 0.0, 0.4, 1.0);
+    assert_eq_approx!(gray, Color::from_rgb(0.4, 0.4, 0.4));
+    assert_eq!(gray.h(), 0.0);
+    assert_eq!(gray.s(), 0.0);
+    assert_eq_approx!(gray.v(), 0.4);
+
+    assert_eq!(Color::BLACK.s(), 0.0);
+    assert_eq!(Color::BLACK.v(), 0.0);
+    assert_eq_approx!(Color::RED.h(), 0.0);
+    assert_eq_approx!(Color::LIME.h(), 1.0 / 3.0);
+    assert_eq_approx!(Color::BLUE.h(), 2.0 / 3.0);
+}
+
 #[itest]
 fn color_hsv_wraps_correctly() {
     for (hue_origin, hue_shift, hue_expected) in [
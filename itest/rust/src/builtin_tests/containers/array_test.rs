@@ -97,6 +97,52 @@ fn array_iter_shared() {
     assert_eq!(iter.next(), None);
 }
 
+#[itest]
+fn array_iter_typed() {
+    let array = varray![Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)];
+    let vectors = array
+        .iter_typed::<Vector2>()
+        .collect::<Result<Vec<_>, _>>()
+        .expect("all elements are Vector2");
+    assert_eq!(vectors, [Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)]);
+
+    // Conversion errors are surfaced per element, iteration continues.
+    let array = varray![Vector2::new(1.0, 2.0), "not a vector", Vector2::ZERO];
+    let results: Vec<_> = array.iter_typed::<Vector2>().collect();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().ok(), Some(&Vector2::new(1.0, 2.0)));
+    let err = results[1].as_ref().expect_err("string is not a Vector2");
+    assert_eq!(err.value(), Some(&"not a vector".to_variant()));
+    assert_eq!(results[2].as_ref().ok(), Some(&Vector2::ZERO));
+
+    let collected = array.iter_typed::<Vector2>().collect::<Result<Vec<_>, _>>();
+    assert!(collected.is_err());
+
+    let empty = VariantArray::new();
+    assert_eq!(empty.iter_typed::<Vector2>().count(), 0);
+}
+
+#[itest]
+fn array_try_to_vec() {
+    let array = varray![1, 2, 3];
+    let ints: Vec<i64> = array.try_to_vec().expect("all elements are int");
+    assert_eq!(ints, [1, 2, 3]);
+
+    // Typed arrays convert infallibly.
+    let typed: Array<GString> = array!["a", "b"];
+    assert_eq!(Vec::from(&typed), [GString::from("a"), GString::from("b")]);
+
+    // Mixed contents: the first bad element is reported.
+    let mixed = varray![1, 2.5, "three"];
+    let err = mixed.try_to_vec::<i64>().expect_err("float is not an int");
+    assert!(err.to_string().contains("index 1"), "{err}");
+
+    let variants: Vec<Variant> = mixed.try_to_vec().expect("everything is a Variant");
+    assert_eq!(variants.len(), 3);
+
+    assert!(VariantArray::new().try_to_vec::<i64>().unwrap().is_empty());
+}
+
 #[itest]
 fn array_hash() {
     let array = array![1, 2];
@@ -447,6 +493,48 @@ fn untyped_array_try_from_typed() {
     node.free();
 }
 
+#[itest]
+fn array_of_optional_builtins() {
+    let mut array = Array::<Option<GString>>::new();
+    array.push(&Some(GString::from("first")));
+    array.push(&None);
+    array.push(&Some(GString::from("third")));
+
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.get(0), Some(Some(GString::from("first"))));
+    assert_eq!(array.get(1), Some(None));
+    assert_eq!(array.get(2), Some(Some(GString::from("third"))));
+
+    // Godot cannot store null in typed arrays, so this is an untyped array.
+    let untyped = array.to_variant().to::<VariantArray>();
+    assert_eq!(untyped, varray!["first", Variant::nil(), "third"]);
+
+    let back = untyped.to_variant().to::<Array<Option<GString>>>();
+    let collected: Vec<Option<GString>> = back.iter_shared().collect();
+    let expected = vec![
+        Some(GString::from("first")),
+        None,
+        Some(GString::from("third")),
+    ];
+    assert_eq!(collected, expected);
+
+    let array = Array::from(&[Some(Vector2::new(1.0, 2.0)), None]);
+    let variant = array.to_variant();
+    assert_eq!(variant.to::<Array<Option<Vector2>>>(), array);
+}
+
+#[itest]
+fn array_of_optional_builtins_bad_element() {
+    let array = varray!["string", Variant::nil(), 12];
+    let converted = array.to_variant().to::<Array<Option<GString>>>();
+
+    assert_eq!(converted.get(0), Some(Some(GString::from("string"))));
+    assert_eq!(converted.get(1), Some(None));
+    expect_panic("INT element cannot convert to Option<GString>", || {
+        converted.get(2);
+    });
+}
+
 #[itest]
 fn array_should_format_with_display() {
     let a = array![1, 2, 3, 4];
@@ -36,6 +36,11 @@ impl CallableTestObj {
         self.value = int;
     }
 
+    #[func]
+    fn digits(&self, hundreds: i64, tens: i64, ones: i64) -> i64 {
+        hundreds * 100 + tens * 10 + ones
+    }
+
     #[func] // static
     fn concat_array(a: i32, b: GString, c: Array<NodePath>, d: Gd<RefCounted>) -> VariantArray {
         varray![a, b, c, d]
@@ -190,6 +195,51 @@ fn callable_static_bind() {
     assert_eq!(4, bind_result_data.len());
 }
 
+#[itest]
+fn callable_try_call() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("digits");
+
+    let result = callable
+        .try_call(&[1.to_variant(), 2.to_variant(), 3.to_variant()])
+        .expect("valid call should succeed");
+    assert_eq!(result, 123.to_variant());
+
+    let err = callable
+        .try_call(&[1.to_variant()])
+        .expect_err("call with too few arguments should fail");
+    assert_eq!(err.method_name(), "call");
+
+    callable
+        .try_call(&[1.to_variant(), 2.to_variant(), "three".to_variant()])
+        .expect_err("call with wrong argument type should fail");
+
+    Callable::invalid()
+        .try_call(&[])
+        .expect_err("call on invalid callable should fail");
+}
+
+#[itest]
+fn callable_bind_argument_order() {
+    let obj = CallableTestObj::new_gd();
+    let callable = obj.callable("digits");
+
+    // Bound arguments are appended after the call arguments.
+    let bound = callable.bind(&[2.to_variant(), 3.to_variant()]);
+    assert!(bound.is_valid());
+    assert_eq!(bound.get_bound_arguments_count(), 2);
+    assert_eq!(bound.call(&[1.to_variant()]), 123.to_variant());
+
+    let bound = callable.bindv(&varray![5, 6]);
+    assert!(bound.is_valid());
+    assert_eq!(bound.get_bound_arguments_count(), 2);
+    assert_eq!(bound.callv(&varray![4]), 456.to_variant());
+
+    // Original callable is unaffected.
+    assert_eq!(callable.get_bound_arguments_count(), 0);
+    assert_eq!(callable.callv(&varray![7, 8, 9]), 789.to_variant());
+}
+
 #[itest]
 fn callable_callv() {
     let obj = CallableTestObj::new_gd();
@@ -408,6 +458,27 @@ pub mod custom_callable {
         assert_eq!(sum2, 0.to_variant());
     }
 
+    #[itest]
+    fn callable_custom_introspection() {
+        let callable = Callable::from_local_fn("sum", sum);
+
+        assert!(callable.is_valid());
+        assert!(callable.is_custom());
+        assert_eq!(callable.object_id(), None);
+
+        // Godot doesn't report names of custom callables; see method_name() docs.
+        crate::framework::suppress_godot_print(|| {
+            assert_eq!(callable.method_name(), None);
+        });
+
+        // A bound method callable remains introspectable.
+        let obj = CallableTestObj::new_gd();
+        let bound = obj.callable("assign_int").bind(&[10.to_variant()]);
+        assert!(bound.is_valid());
+        assert!(bound.is_custom());
+        assert_eq!(bound.object_id(), Some(obj.instance_id()));
+    }
+
     // Without this feature, any access to the global binding from another thread fails; so the from_local_fn() cannot be tested in isolation.
     #[itest]
     fn callable_from_local_fn_crossthread() {
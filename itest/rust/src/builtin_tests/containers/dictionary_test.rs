@@ -7,7 +7,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{dict, varray, Dictionary, Variant};
+use godot::builtin::{dict, varray, Dictionary, GString, Variant};
 use godot::meta::{FromGodot, ToGodot};
 use godot::sys::GdextBuild;
 
@@ -210,6 +210,43 @@ fn dictionary_get() {
     assert_eq!(dictionary.get("foobar"), None, "key = \"foobar\"");
 }
 
+#[itest]
+fn dictionary_get_typed() {
+    let dictionary = dict! {
+        "int": 42,
+        "text": "hello",
+        "nil": Variant::nil(),
+    };
+
+    // Present and convertible.
+    assert_eq!(dictionary.get_typed::<_, i64>("int"), Some(42));
+    assert_eq!(
+        dictionary.get_typed::<_, GString>("text"),
+        Some(GString::from("hello"))
+    );
+    assert_eq!(
+        dictionary.get_typed::<_, Option<GString>>("nil"),
+        Some(None)
+    );
+
+    // Absent.
+    assert_eq!(dictionary.get_typed::<_, i64>("missing"), None);
+    assert!(matches!(
+        dictionary.get_typed_result::<_, i64>("missing"),
+        Ok(None)
+    ));
+
+    // Present, but wrong type.
+    assert_eq!(dictionary.get_typed::<_, i64>("text"), None);
+    let err = dictionary
+        .get_typed_result::<_, i64>("text")
+        .expect_err("STRING -> int must fail");
+    assert_eq!(err.value(), Some(&"hello".to_variant()));
+
+    let ok = dictionary.get_typed_result::<_, i64>("int").unwrap();
+    assert_eq!(ok, Some(42));
+}
+
 #[itest]
 fn dictionary_at() {
     let dictionary = dict! {
@@ -301,6 +338,43 @@ fn dictionary_extend() {
     assert_eq!(dictionary.get("bar"), Some("new".to_variant()));
 }
 
+#[itest]
+fn dictionary_extend_collisions() {
+    let defaults = dict! {
+        "volume": 50,
+        "fullscreen": false,
+        "keys": dict! { "jump": "space", "fire": "ctrl" },
+    };
+    let user = dict! {
+        "volume": 80,
+        "keys": dict! { "jump": "w" },
+    };
+
+    // Layering user settings over defaults.
+    let mut settings = defaults.duplicate_shallow();
+    settings.extend_dictionary(&user, true);
+    assert_eq!(settings.len(), 3);
+    assert_eq!(settings.at("volume"), 80.to_variant());
+    assert_eq!(settings.at("fullscreen"), false.to_variant());
+
+    // Merge is shallow: the nested dictionary is replaced, not merged.
+    let keys = settings.at("keys").to::<Dictionary>();
+    assert_eq!(keys, dict! { "jump": "w" });
+
+    // Without overwrite, existing keys are kept, including nested ones.
+    let mut settings = defaults.duplicate_shallow();
+    settings.extend_dictionary(&user, false);
+    assert_eq!(settings, defaults);
+
+    // Merging with itself is a no-op.
+    let mut dictionary = dict! { "a": 1, "b": 2 };
+    let same = dictionary.clone();
+    dictionary.extend_dictionary(&same, true);
+    assert_eq!(dictionary, dict! { "a": 1, "b": 2 });
+    dictionary.extend_dictionary(&same, false);
+    assert_eq!(dictionary.len(), 2);
+}
+
 #[itest]
 fn dictionary_remove() {
     let mut dictionary = dict! {
@@ -333,6 +407,33 @@ fn dictionary_find_key() {
 
     assert_eq!(dictionary.find_key_by_value(0), Some("foo".to_variant()));
     assert_eq!(dictionary.find_key_by_value(true), Some("bar".to_variant()));
+    assert_eq!(dictionary.find_key_by_value("missing"), None);
+}
+
+#[itest]
+fn dictionary_find_key_duplicates_and_nil() {
+    // Duplicate values: first key in insertion order wins.
+    let dictionary = dict! {
+        "first": 1,
+        "second": 2,
+        "third": 1,
+    };
+    assert_eq!(dictionary.find_key_by_value(1), Some("first".to_variant()));
+    assert_eq!(dictionary.find_key_by_value(2), Some("second".to_variant()));
+
+    // NIL key is distinguished from "not found".
+    let mut dictionary = dict! { "a": 1 };
+    dictionary.set(Variant::nil(), 5);
+    assert_eq!(dictionary.find_key_by_value(5), Some(Variant::nil()));
+    assert_eq!(dictionary.find_key_by_value(1), Some("a".to_variant()));
+    assert_eq!(dictionary.find_key_by_value(7), None);
+
+    // NIL values are found too.
+    let dictionary = dict! { "none": Variant::nil() };
+    assert_eq!(
+        dictionary.find_key_by_value(Variant::nil()),
+        Some("none".to_variant())
+    );
 }
 
 #[itest]
@@ -353,6 +454,17 @@ fn dictionary_contains_keys() {
         !dictionary.contains_all_keys(&varray!["foo", "bar", "missing"]),
         "keys = [\"foo\", \"bar\", \"missing\"]"
     );
+
+    // Schema-style checks.
+    assert!(dictionary.contains_all_keys(&varray![]), "no keys");
+    assert!(
+        dictionary.contains_all_keys(&varray!["foo", "foo"]),
+        "duplicate keys"
+    );
+    assert!(
+        !dict! {}.contains_all_keys(&varray!["foo"]),
+        "empty dictionary"
+    );
 }
 
 #[itest]
@@ -399,6 +511,45 @@ fn dictionary_iter() {
     assert_eq!(map, map2);
 }
 
+#[itest]
+fn dictionary_iter_order() {
+    let mut dictionary = dict! {
+        "b": 1,
+        "a": 2,
+        "c": 3,
+    };
+
+    let keys: Vec<String> = dictionary.keys_shared().typed().collect();
+    assert_eq!(keys, ["b", "a", "c"]);
+    assert_eq!(dictionary.keys_array(), varray!["b", "a", "c"]);
+    assert_eq!(dictionary.values_array(), varray![1, 2, 3]);
+
+    // Overwriting keeps the position, re-inserting moves the key to the end.
+    dictionary.set("a", 20);
+    dictionary.remove("b");
+    dictionary.set("b", 10);
+
+    let pairs: Vec<(String, i64)> = dictionary.iter_shared().typed().collect();
+    assert_eq!(
+        pairs,
+        [
+            ("a".to_string(), 20),
+            ("c".to_string(), 3),
+            ("b".to_string(), 10)
+        ]
+    );
+
+    let map: HashMap<String, i64> = dictionary.iter_shared().typed().collect();
+    assert_eq!(
+        map,
+        HashMap::from([
+            ("a".to_string(), 20),
+            ("b".to_string(), 10),
+            ("c".to_string(), 3)
+        ])
+    );
+}
+
 #[itest]
 fn dictionary_iter_size_hint() {
     // Test a completely empty dict.
@@ -7,9 +7,11 @@
 
 use crate::framework::{expect_panic, itest};
 use godot::builtin::{
-    dict, Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt32Array,
-    PackedStringArray, Variant,
+    dict, real, Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array,
+    PackedInt32Array, PackedInt64Array, PackedStringArray, PackedVector2Array, PackedVector3Array,
+    Variant, Vector2, Vector3,
 };
+use godot::classes::file_access::CompressionMode;
 use godot::prelude::ToGodot;
 
 #[itest]
@@ -171,6 +173,145 @@ fn packed_array_as_slice() {
     assert_eq!(empty.as_slice(), &[]);
 }
 
+#[itest]
+fn packed_vector_array_as_slice() {
+    let points = [
+        Vector2::new(1.0, 2.0),
+        Vector2::ZERO,
+        Vector2::new(-3.5, 4.25),
+    ];
+    let array = PackedVector2Array::from(&points);
+    assert_eq!(array.as_slice(), &points);
+
+    // Slice algorithms work directly on Godot's storage.
+    let max_x = array
+        .as_slice()
+        .iter()
+        .map(|v| v.x)
+        .fold(real::MIN, real::max);
+    assert_eq!(max_x, 1.0);
+
+    let vertices = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+    let mut array = PackedVector3Array::from(&vertices);
+    assert_eq!(array.as_slice(), &vertices);
+
+    // Elements are laid out as in Godot: they are visible the same way through get().
+    array.as_mut_slice()[1].y = -5.0;
+    assert_eq!(array.get(1), Some(Vector3::new(4.0, -5.0, 6.0)));
+    assert_eq!(
+        array.as_slice().iter().copied().sum::<Vector3>(),
+        Vector3::new(5.0, -3.0, 9.0)
+    );
+
+    assert_eq!(PackedVector2Array::new().as_slice(), &[]);
+    assert_eq!(PackedVector3Array::new().as_slice(), &[]);
+}
+
+#[itest]
+fn packed_string_array_vec_string_roundtrip() {
+    let strings = vec![
+        String::from("hello"),
+        String::new(),
+        String::from("Grüße"),
+        String::from("日本語"),
+        String::from("emoji 🦀"),
+    ];
+
+    let array: PackedStringArray = strings.iter().cloned().collect();
+    assert_eq!(array.len(), strings.len());
+    assert_eq!(array[2], GString::from("Grüße"));
+    assert_eq!(array.to_vec_string(), strings);
+
+    let from_strs: PackedStringArray = strings.iter().map(String::as_str).collect();
+    assert_eq!(from_strs, array);
+
+    let empty: PackedStringArray = Vec::<String>::new().into_iter().collect();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_vec_string(), Vec::<String>::new());
+
+    // Godot doesn't guarantee that NUL characters are preserved, but conversion must not fail. The prefix always survives.
+    let with_nul: PackedStringArray = ["before\0after"].into_iter().collect();
+    assert_eq!(with_nul.len(), 1);
+    assert!(with_nul.to_vec_string()[0].starts_with("before"));
+}
+
+#[itest]
+fn packed_string_array_join() {
+    let array: PackedStringArray = ["a", "bc", "", "d"].into_iter().collect();
+    assert_eq!(array.join(", "), GString::from("a, bc, , d"));
+    assert_eq!(array.join(""), GString::from("abcd"));
+    assert_eq!(array.join("\n"), GString::from("a\nbc\n\nd"));
+
+    let single: PackedStringArray = ["only"].into_iter().collect();
+    assert_eq!(single.join(", "), GString::from("only"));
+
+    let empty = PackedStringArray::new();
+    assert!(empty.join(", ").is_empty());
+
+    // Same result as GDScript's `separator.join(array)`.
+    assert_eq!(array.join("-"), GString::from("-").join(&array));
+}
+
+#[itest]
+fn packed_byte_array_bools() {
+    let pattern = [true, false, false, true, true, false, true];
+    let bytes = PackedByteArray::from_bools(&pattern);
+    assert_eq!(bytes.as_slice(), &[1, 0, 0, 1, 1, 0, 1]);
+    assert_eq!(bytes.to_bools(), pattern);
+
+    // Any nonzero byte is true.
+    let bytes = PackedByteArray::from(&[0, 2, 255, 0, 1]);
+    assert_eq!(bytes.to_bools(), [false, true, true, false, true]);
+
+    let empty = PackedByteArray::from_bools(&[]);
+    assert!(empty.is_empty());
+    assert!(empty.to_bools().is_empty());
+}
+
+#[cfg(feature = "bytemuck")]
+#[itest]
+fn packed_byte_array_pod_roundtrip() {
+    #[repr(C)]
+    #[derive(Copy, Clone, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+    struct Point {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+
+    let points = [
+        Point {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        },
+        Point {
+            x: -0.5,
+            y: 0.0,
+            z: f32::MAX,
+        },
+    ];
+
+    let bytes = PackedByteArray::from_pod_slice(&points);
+    assert_eq!(bytes.len(), 2 * size_of::<Point>());
+    assert_eq!(bytes.to_pod_vec::<Point>().unwrap(), points);
+
+    // Different element sizes reinterpret the same bytes.
+    let floats = bytes.to_pod_vec::<f32>().expect("length is multiple of 4");
+    assert_eq!(floats, [1.0, 2.0, 3.0, -0.5, 0.0, f32::MAX]);
+
+    // Length not divisible by element size.
+    let mut truncated = bytes.clone();
+    truncated.resize(bytes.len() - 1);
+    truncated
+        .to_pod_vec::<Point>()
+        .expect_err("length not divisible by element size");
+
+    let empty = PackedByteArray::from_pod_slice::<Point>(&[]);
+    assert!(empty.is_empty());
+    assert!(empty.to_pod_vec::<Point>().unwrap().is_empty());
+}
+
 #[itest]
 fn packed_array_as_mut_slice() {
     let a = PackedByteArray::from(&[1, 2, 3]);
@@ -191,6 +332,30 @@ fn packed_array_as_mut_slice() {
     assert_eq!(empty.as_mut_slice(), &mut []);
 }
 
+#[itest]
+fn packed_array_as_mut_slice_writes_through() {
+    let mut bytes = PackedByteArray::from(&[1, 2, 3, 4]);
+    let slice = bytes.as_mut_slice();
+    assert_eq!(slice.len(), 4);
+    slice.copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+
+    // Godot-side operations observe the modification.
+    assert_eq!(bytes.hex_encode(), GString::from("deadbeef"));
+    assert_eq!(bytes.find(0xbe, None), Some(2));
+
+    let mut floats = PackedFloat32Array::from(&[1.0, 2.0]);
+    floats.as_mut_slice()[1] = 5.5;
+    assert_eq!(floats.as_slice().len(), floats.len());
+    assert_eq!(floats.find(5.5f32, None), Some(1));
+
+    let mut colors = PackedColorArray::from(&[Color::RED]);
+    colors.as_mut_slice()[0] = Color::BLUE;
+    assert_eq!(
+        colors.to_variant().to::<PackedColorArray>().as_slice(),
+        &[Color::BLUE]
+    );
+}
+
 #[itest]
 fn packed_array_index() {
     let array = PackedByteArray::from(&[1, 2]);
@@ -352,6 +517,55 @@ fn packed_array_format() {
     assert_eq!(format!("{a}"), "[]");
 }
 
+#[itest]
+fn packed_int_array_widen_narrow() {
+    let ints32 = PackedInt32Array::from(&[0, -1, i32::MIN, i32::MAX]);
+    let ints64 = ints32.to_int64();
+    assert_eq!(
+        ints64.as_slice(),
+        &[0, -1, i32::MIN as i64, i32::MAX as i64]
+    );
+    assert_eq!(ints64.to_int32().expect("values fit into i32"), ints32);
+
+    assert!(PackedInt32Array::new().to_int64().is_empty());
+    assert!(PackedInt64Array::new().to_int32().unwrap().is_empty());
+
+    let too_large = PackedInt64Array::from(&[1, 2, i32::MAX as i64 + 1, i64::MIN]);
+    let err = too_large.to_int32().expect_err("narrowing overflow");
+    assert!(err.to_string().contains("index 2"), "{err}");
+    assert_eq!(err.value(), Some(&(i32::MAX as i64 + 1).to_variant()));
+
+    let too_small = PackedInt64Array::from(&[i32::MIN as i64 - 1]);
+    too_small.to_int32().expect_err("narrowing underflow");
+}
+
+#[itest]
+fn packed_byte_array_from_slice_as_ref() {
+    fn checksum(bytes: impl AsRef<[u8]>) -> u64 {
+        bytes.as_ref().iter().map(|&b| u64::from(b)).sum()
+    }
+
+    let empty = PackedByteArray::from(&[][..]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.as_ref(), &[] as &[u8]);
+    assert_eq!(checksum(&empty), 0);
+
+    let small = PackedByteArray::from(b"godot".as_slice());
+    assert_eq!(small.as_ref(), b"godot");
+    assert_eq!(checksum(&small), checksum(b"godot"));
+
+    // Large buffer: content must survive the bulk copy unchanged.
+    let large: Vec<u8> = (0..4 * 1024 * 1024).map(|i| (i * 31 % 251) as u8).collect();
+    let packed = PackedByteArray::from(large.as_slice());
+    assert_eq!(packed.len(), large.len());
+    assert_eq!(packed.as_ref(), large.as_slice());
+
+    // Works for other packed arrays too.
+    let ints = PackedInt32Array::from(&[1, 2, 3]);
+    let slice: &[i32] = ints.as_ref();
+    assert_eq!(slice, &[1, 2, 3]);
+}
+
 #[itest]
 fn packed_byte_array_encode_decode() {
     let a = PackedByteArray::from(&[0xAB, 0xCD, 0x12]);
@@ -369,6 +583,40 @@ fn packed_byte_array_encode_decode() {
     assert_eq!(a.decode_u8(2), Ok(0xEF));
 }
 
+#[itest]
+fn packed_byte_array_encode_decode_mixed() {
+    let mut a = PackedByteArray::from(&[0u8; 16]);
+
+    a.encode_u32(0, 0xDEAD_BEEF).unwrap();
+    a.encode_float(4, -1.5).unwrap();
+    a.encode_double(8, 1234.0625).unwrap();
+
+    assert_eq!(a.decode_u32(0), Ok(0xDEAD_BEEF));
+    assert_eq!(a.decode_float(4), Ok(-1.5));
+    assert_eq!(a.decode_double(8), Ok(1234.0625));
+
+    // Same layout as little-endian Rust encoding.
+    assert_eq!(a.as_slice()[0..4], 0xDEAD_BEEFu32.to_le_bytes());
+    assert_eq!(a.as_slice()[4..8], (-1.5f32).to_le_bytes());
+    assert_eq!(a.as_slice()[8..16], 1234.0625f64.to_le_bytes());
+
+    // Unaligned offsets.
+    a.encode_u32(3, 0x0102_0304).unwrap();
+    assert_eq!(a.decode_u32(3), Ok(0x0102_0304));
+    assert_eq!(a.decode_u8(3), Ok(0x04));
+    assert_eq!(a.decode_u8(6), Ok(0x01));
+
+    // Out of bounds: nothing read or written.
+    let before = a.clone();
+    assert_eq!(a.decode_double(9), Err(()));
+    assert_eq!(a.decode_u32(13), Err(()));
+    assert_eq!(a.decode_u8(16), Err(()));
+    assert_eq!(a.decode_u32(usize::MAX), Err(()));
+    assert_eq!(a.encode_float(13, 1.0), Err(()));
+    assert_eq!(a.encode_u64(usize::MAX - 2, 1), Err(()));
+    assert_eq!(a, before);
+}
+
 #[itest]
 fn packed_byte_array_encode_decode_variant() {
     let variant = dict! {
@@ -419,3 +667,41 @@ fn packed_byte_array_encode_decode_variant() {
     assert_eq!(decoded.0, Variant::nil());
     assert_eq!(decoded.1, 0);
 }
+
+#[itest]
+fn packed_byte_array_compress_decompress() {
+    // Repetitive data, so that every mode actually shrinks it.
+    let text = "save game payload, ".repeat(50);
+    let original = PackedByteArray::from(text.as_bytes());
+
+    let modes = [
+        CompressionMode::FASTLZ,
+        CompressionMode::DEFLATE,
+        CompressionMode::ZSTD,
+        CompressionMode::GZIP,
+    ];
+
+    for mode in modes {
+        let compressed = original.compress(mode).expect("compress() succeeds");
+        assert!(compressed.len() < original.len(), "{mode:?} shrinks data");
+
+        let decompressed = compressed
+            .decompress(original.len(), mode)
+            .expect("decompress() succeeds");
+        assert_eq!(decompressed, original, "{mode:?} round-trip");
+
+        // Buffer too small for the uncompressed data.
+        let truncated = compressed.decompress(original.len() / 2, mode);
+        assert_eq!(truncated, Err(()), "{mode:?} with too small buffer");
+    }
+
+    // Dynamic buffer size: only supported by some modes.
+    for mode in [CompressionMode::DEFLATE, CompressionMode::GZIP] {
+        let compressed = original.compress(mode).unwrap();
+
+        let decompressed = compressed
+            .decompress_dynamic(None, mode)
+            .expect("decompress_dynamic() succeeds");
+        assert_eq!(decompressed, original, "{mode:?} dynamic round-trip");
+    }
+}
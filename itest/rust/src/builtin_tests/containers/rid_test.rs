@@ -8,6 +8,7 @@
 use godot::builtin::inner::InnerRid;
 use godot::builtin::Rid;
 use godot::classes::RenderingServer;
+use godot::meta::ToGodot;
 
 use crate::framework::{itest, suppress_godot_print};
 
@@ -22,6 +23,28 @@ fn rid_equiv() {
     assert_eq!(InnerRid::from_outer(&valid).get_id(), (10 << 32) | 20);
 }
 
+#[itest]
+fn rid_from_to_u64() {
+    let id = (10 << 32) | 20;
+    let rid = Rid::from_u64(id);
+    assert!(rid.is_valid());
+    assert!(!rid.is_invalid());
+    assert_eq!(rid.to_u64(), id);
+    assert_eq!(rid.to_valid_u64(), Some(id));
+    assert_eq!(rid, Rid::new(id));
+
+    let zero = Rid::from_u64(0);
+    assert_eq!(zero, Rid::Invalid);
+    assert!(!zero.is_valid());
+    assert!(zero.is_invalid());
+    assert_eq!(zero.to_u64(), 0);
+    assert_eq!(zero.to_valid_u64(), None);
+    assert!(!InnerRid::from_outer(&zero).is_valid());
+
+    // The invalid RID survives the round-trip through Godot.
+    assert_eq!(zero.to_variant().to::<Rid>(), Rid::Invalid);
+}
+
 #[itest]
 fn canvas_set_parent() {
     // This originally caused UB, but still testing it here in case it breaks.
@@ -239,6 +239,70 @@ fn signal_construction_and_id() {
     assert_eq!(signal.object(), None);
 }
 
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_connect_flags() {
+    use crate::framework::suppress_godot_print;
+    use godot::builtin::{Callable, Variant};
+    use godot::classes::object::ConnectFlags;
+    use godot::global::Error;
+
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("test_signal");
+    let signal = Signal::from_object_signal(&object, "test_signal");
+
+    let counter = Rc::new(Cell::new(0));
+    let callable = {
+        let counter = counter.clone();
+        Callable::from_local_fn("count", move |_args| {
+            counter.set(counter.get() + 1);
+            Ok(Variant::nil())
+        })
+    };
+
+    // Regular connection; connecting twice fails and keeps a single connection.
+    assert!(!signal.is_connected(&callable));
+    assert_eq!(
+        signal.connect_flags(&callable, ConnectFlags::default()),
+        Error::OK
+    );
+    assert!(signal.is_connected(&callable));
+
+    let mut second = Error::OK;
+    suppress_godot_print(|| second = signal.connect_flags(&callable, ConnectFlags::default()));
+    assert_eq!(second, Error::ERR_INVALID_PARAMETER);
+    assert_eq!(signal.connections().len(), 1);
+
+    signal.emit(&[]);
+    assert_eq!(counter.get(), 1);
+
+    signal.disconnect(&callable);
+    assert!(!signal.is_connected(&callable));
+
+    // Disconnecting a non-connected callable only reports an error.
+    suppress_godot_print(|| signal.disconnect(&callable));
+    assert!(!signal.is_connected(&callable));
+
+    // One-shot connections are removed after the first emission.
+    assert_eq!(
+        signal.connect_flags(&callable, ConnectFlags::ONE_SHOT),
+        Error::OK
+    );
+    signal.emit(&[]);
+    signal.emit(&[]);
+    assert_eq!(counter.get(), 2);
+    assert!(!signal.is_connected(&callable));
+
+    // Reference-counted connections can be added multiple times and must be disconnected as often.
+    let flags = ConnectFlags::REFERENCE_COUNTED;
+    assert_eq!(signal.connect_flags(&callable, flags), Error::OK);
+    assert_eq!(signal.connect_flags(&callable, flags), Error::OK);
+    signal.disconnect(&callable);
+    assert!(signal.is_connected(&callable));
+    signal.disconnect(&callable);
+    assert!(!signal.is_connected(&callable));
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper types
 
@@ -7,18 +7,28 @@
 
 use std::cmp::Ordering;
 use std::fmt::Display;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroU64, NonZeroU8};
+use std::ops::{Range, RangeInclusive};
+use std::path::PathBuf;
+use std::time::Duration;
 
+use godot::builtin::math::ApproxEq;
 use godot::builtin::{
-    array, dict, varray, Array, GString, NodePath, Signal, StringName, Variant, Vector2, Vector3,
+    array, dict, varray, Array, GString, NodePath, Signal, StringName, Variant, Vector2, Vector2i,
+    Vector3,
+};
+use godot::builtin::{
+    Basis, Color, Dictionary, PackedByteArray, PackedInt32Array, Rect2, VariantArray,
+    VariantOperator, VariantType,
 };
-use godot::builtin::{Basis, Dictionary, VariantArray, VariantOperator, VariantType};
 use godot::classes::{Node, Node2D};
 use godot::meta::{FromGodot, ToGodot};
-use godot::obj::{Gd, InstanceId, NewAlloc};
+use godot::obj::{EngineEnum, Gd, InstanceId, NewAlloc};
 use godot::sys::GodotFfi;
 
 use crate::common::roundtrip;
-use crate::framework::{expect_panic, itest, runs_release};
+use crate::framework::{expect_panic, itest, runs_release, suppress_godot_print};
 
 const TEST_BASIS: Basis = Basis::from_rows(
     Vector3::new(1.0, 2.0, 3.0),
@@ -60,6 +70,13 @@ fn variant_conversions() {
     roundtrip(-2147483648i32);
     roundtrip(9223372036854775807i64);
 
+    // u64 (bit-reinterpreted as i64)
+    roundtrip(0u64);
+    roundtrip(u64::MAX);
+    roundtrip(i64::MAX as u64);
+    roundtrip(i64::MAX as u64 + 1);
+    roundtrip(i64::MAX as u64 - 1);
+
     // string
     roundtrip(gstr("some string"));
     roundtrip(String::from("some other string"));
@@ -93,8 +110,35 @@ fn variant_bad_integer_conversions() {
 
     truncate_bad::<u32>(4294967296);
     truncate_bad::<u32>(-1);
+}
 
-    truncate_bad::<u64>(-1);
+#[itest]
+fn variant_integer_boundaries() {
+    macro_rules! check_bounds {
+        ($($T:ty),*) => {$(
+            let min = <$T>::MIN as i64;
+            let max = <$T>::MAX as i64;
+
+            // Stored as INT, boundaries convert back exactly.
+            assert_eq!(<$T>::MIN.to_variant().get_type(), VariantType::INT);
+            assert_eq!(min.to_variant().to::<$T>(), <$T>::MIN);
+            assert_eq!(max.to_variant().to::<$T>(), <$T>::MAX);
+
+            // One beyond either boundary is rejected, naming the target type.
+            for out_of_range in [min - 1, max + 1] {
+                let err = out_of_range
+                    .to_variant()
+                    .try_to::<$T>()
+                    .expect_err("out-of-range INT must fail");
+
+                let expected = concat!("`", stringify!($T), "` cannot store the given value");
+                assert!(err.to_string().contains(expected), "unexpected error: {err}");
+                assert_eq!(err.value(), Some(&out_of_range.to_variant()));
+            }
+        )*};
+    }
+
+    check_bounds!(i8, i16, i32, u8, u16, u32);
 }
 
 #[itest]
@@ -115,11 +159,6 @@ fn variant_bad_conversions() {
     assert_convert_err::<_, String>(false);
     assert_convert_err::<_, StringName>(VariantArray::default());
 
-    // Special case: ToVariant is not yet fallible, so u64 -> i64 conversion error panics.
-    expect_panic("u64 -> i64 conversion error", || {
-        u64::MAX.to_variant();
-    });
-
     //assert_eq!(
     //    Dictionary::default().to_variant().try_to::<Array>(),
     //    Err(VariantConversionError)
@@ -130,6 +169,525 @@ fn variant_bad_conversions() {
         .expect_err("`nil` should not convert to `Dictionary`");
 }
 
+#[itest]
+fn variant_get() {
+    let variant = Vector2::new(1.0, 2.0).to_variant();
+    assert_eq!(variant.get::<Vector2>(), Some(Vector2::new(1.0, 2.0)));
+    assert_eq!(variant.get::<GString>(), None);
+
+    let variant = gstr("hello").to_variant();
+    assert_eq!(variant.get::<GString>(), Some(gstr("hello")));
+    assert_eq!(variant.get::<i64>(), None);
+
+    assert_eq!(Variant::nil().get::<()>(), Some(()));
+    assert_eq!(Variant::nil().get::<Vector2>(), None);
+    assert_eq!(12.to_variant().get::<()>(), None);
+}
+
+#[itest]
+fn variant_try_from() {
+    let variant = Vector2::new(1.0, 2.0).to_variant();
+    let by_ref: Vector2 = (&variant).try_into().unwrap();
+    assert_eq!(by_ref, Vector2::new(1.0, 2.0));
+    let owned: Vector2 = variant.try_into().unwrap();
+    assert_eq!(owned, Vector2::new(1.0, 2.0));
+
+    let variant = gstr("hello").to_variant();
+    assert_eq!(GString::try_from(&variant).unwrap(), gstr("hello"));
+    assert_eq!(GString::try_from(variant.clone()).unwrap(), gstr("hello"));
+
+    let variant = 42.to_variant();
+    assert_eq!(i64::try_from(&variant).unwrap(), 42);
+    assert_eq!(i64::try_from(variant.clone()).unwrap(), 42);
+
+    let variant = dict! { "key": 1 }.to_variant();
+    let dict = Dictionary::try_from(&variant).unwrap();
+    assert_eq!(dict.get("key"), Some(1.to_variant()));
+
+    // Mismatches.
+    let err = Vector2::try_from(&gstr("hello").to_variant()).unwrap_err();
+    assert_eq!(err.expected_type(), Some(VariantType::VECTOR2));
+    bool::try_from(Variant::nil()).unwrap_err();
+    StringName::try_from(&42.to_variant()).unwrap_err();
+}
+
+#[itest]
+fn variant_u64_reinterpretation() {
+    assert_eq!(u64::MAX.to_variant(), (-1i64).to_variant());
+    assert_eq!((i64::MAX as u64 + 1).to_variant(), i64::MIN.to_variant());
+    assert_eq!(12345u64.to_variant(), 12345i64.to_variant());
+
+    assert_eq!((-1i64).to_variant().to::<u64>(), u64::MAX);
+    assert_eq!(i64::MIN.to_variant().to::<u64>(), i64::MAX as u64 + 1);
+}
+
+#[itest]
+fn variant_f32_conversions() {
+    // Exactly representable values round-trip.
+    roundtrip(0.0f32);
+    roundtrip(-1.5f32);
+    roundtrip(f32::MAX);
+    roundtrip(f32::MIN_POSITIVE);
+    roundtrip(f32::INFINITY);
+
+    // Widening to FLOAT is exact.
+    assert_eq!(0.1f32.to_variant().to::<f64>(), 0.1f32 as f64);
+
+    // Narrowing loses precision, rounding to nearest.
+    let narrowed = 0.1f64.to_variant().to::<f32>();
+    assert_eq!(narrowed, 0.1f32);
+    assert_ne!(narrowed as f64, 0.1f64);
+
+    let narrowed = (1.0 + f64::EPSILON).to_variant().to::<f32>();
+    assert_eq!(narrowed, 1.0f32);
+
+    // Out-of-range values saturate to infinity.
+    assert_eq!(1e300.to_variant().to::<f32>(), f32::INFINITY);
+    assert_eq!((-1e300).to_variant().to::<f32>(), f32::NEG_INFINITY);
+    assert_eq!(
+        (f32::MAX as f64 * 2.0).to_variant().to::<f32>(),
+        f32::INFINITY
+    );
+
+    // Subnormals: values below the smallest f32 subnormal round to zero, others to the nearest subnormal.
+    let smallest_subnormal = f32::from_bits(1);
+    assert_eq!(
+        (smallest_subnormal as f64).to_variant().to::<f32>(),
+        smallest_subnormal
+    );
+    assert_eq!(1e-50.to_variant().to::<f32>(), 0.0f32);
+
+    assert!(f64::NAN.to_variant().to::<f32>().is_nan());
+}
+
+#[itest]
+fn variant_nonzero_conversions() {
+    roundtrip(NonZeroI64::new(-5).unwrap());
+    roundtrip(NonZeroI64::MAX);
+    roundtrip(NonZeroU32::new(42).unwrap());
+    roundtrip(NonZeroU32::MAX);
+    roundtrip(NonZeroU8::MIN);
+    roundtrip(NonZeroU64::MAX);
+
+    let variant = NonZeroU32::new(7).unwrap().to_variant();
+    assert_eq!(variant.get_type(), VariantType::INT);
+    assert_eq!(variant.to::<i64>(), 7);
+
+    // Zero is rejected, as are values out of the primitive's range.
+    assert!(0.to_variant().try_to::<NonZeroI64>().is_err());
+    assert!(0.to_variant().try_to::<NonZeroU32>().is_err());
+    assert!((-1).to_variant().try_to::<NonZeroU32>().is_err());
+    assert!(256.to_variant().try_to::<NonZeroU8>().is_err());
+    assert!(1.5.to_variant().try_to::<NonZeroI32>().is_err());
+}
+
+#[itest]
+fn variant_range_conversions() {
+    roundtrip(0..10);
+    roundtrip(-5..5);
+    roundtrip(i32::MIN..i32::MAX);
+    roundtrip(3..=7);
+    roundtrip(0..=0);
+
+    // Inverted and empty ranges are preserved.
+    roundtrip(10..2);
+    roundtrip(4..4);
+
+    let variant = (2..8).to_variant();
+    assert_eq!(variant.get_type(), VariantType::VECTOR2I);
+    assert_eq!(variant.to::<Vector2i>(), Vector2i::new(2, 8));
+    assert_eq!(
+        Vector2i::new(-1, 3)
+            .to_variant()
+            .to::<RangeInclusive<i32>>(),
+        -1..=3
+    );
+
+    assert!(Vector2::new(1.0, 2.0)
+        .to_variant()
+        .try_to::<Range<i32>>()
+        .is_err());
+}
+
+#[itest]
+fn variant_char_conversions() {
+    roundtrip('a');
+    roundtrip('ö');
+    roundtrip('🍎'); // Astral plane.
+
+    let variant = 'x'.to_variant();
+    assert_eq!(variant.get_type(), VariantType::STRING);
+    assert_eq!(variant.to::<GString>(), gstr("x"));
+    assert_eq!(gstr("💡").to_variant().to::<char>(), '💡');
+
+    // Only single-character strings are accepted.
+    assert!(gstr("").to_variant().try_to::<char>().is_err());
+    assert!(gstr("ab").to_variant().try_to::<char>().is_err());
+    assert!(gstr("🍎🍏").to_variant().try_to::<char>().is_err());
+    assert!(65.to_variant().try_to::<char>().is_err());
+}
+
+#[itest]
+fn variant_ip_addr_conversions() {
+    roundtrip(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)));
+    roundtrip(IpAddr::V6(Ipv6Addr::LOCALHOST));
+    roundtrip(Ipv4Addr::UNSPECIFIED);
+    roundtrip("fe80::1:2:3".parse::<Ipv6Addr>().unwrap());
+    roundtrip(SocketAddr::from(([127, 0, 0, 1], 8080)));
+    roundtrip("[2001:db8::1]:443".parse::<SocketAddr>().unwrap());
+
+    // IPv6 hosts without brackets, socket addresses with brackets.
+    let variant = IpAddr::V6(Ipv6Addr::LOCALHOST).to_variant();
+    assert_eq!(variant.get_type(), VariantType::STRING);
+    assert_eq!(variant.to::<GString>(), gstr("::1"));
+
+    let socket = SocketAddr::from((Ipv6Addr::LOCALHOST, 9000));
+    assert_eq!(socket.to_variant().to::<GString>(), gstr("[::1]:9000"));
+    assert_eq!(
+        gstr("10.0.0.2:27015").to_variant().to::<SocketAddr>(),
+        SocketAddr::from(([10, 0, 0, 2], 27015))
+    );
+
+    // Invalid formats.
+    gstr("localhost")
+        .to_variant()
+        .try_to::<IpAddr>()
+        .expect_err("host name");
+    gstr("256.0.0.1")
+        .to_variant()
+        .try_to::<Ipv4Addr>()
+        .expect_err("out of range");
+    gstr("::1:8080")
+        .to_variant()
+        .try_to::<SocketAddr>()
+        .expect_err("IPv6 without brackets");
+    gstr("127.0.0.1")
+        .to_variant()
+        .try_to::<SocketAddr>()
+        .expect_err("missing port");
+    5.to_variant().try_to::<IpAddr>().expect_err("not a string");
+}
+
+#[cfg(feature = "uuid")]
+#[itest]
+fn variant_uuid_conversions() {
+    use uuid::Uuid;
+
+    let uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+    roundtrip(uuid);
+    roundtrip(Uuid::nil());
+
+    // String form: lowercase, hyphenated.
+    let variant = uuid.to_variant();
+    assert_eq!(variant.get_type(), VariantType::STRING);
+    assert_eq!(
+        variant.to::<GString>(),
+        gstr("67e55044-10b1-426f-9247-bb680e5fe0c8")
+    );
+
+    // Other accepted input formats.
+    assert_eq!(
+        gstr("67E55044-10B1-426F-9247-BB680E5FE0C8")
+            .to_variant()
+            .to::<Uuid>(),
+        uuid
+    );
+    assert_eq!(
+        gstr("67e5504410b1426f9247bb680e5fe0c8")
+            .to_variant()
+            .to::<Uuid>(),
+        uuid
+    );
+
+    gstr("67e55044-10b1-426f-9247")
+        .to_variant()
+        .try_to::<Uuid>()
+        .expect_err("too short");
+    gstr("not-a-uuid")
+        .to_variant()
+        .try_to::<Uuid>()
+        .expect_err("malformed");
+
+    // Byte form: 16 bytes, big-endian.
+    let bytes = PackedByteArray::from_uuid(uuid);
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(bytes.as_slice(), uuid.as_bytes());
+    let back = bytes.to_variant().to::<PackedByteArray>().to_uuid();
+    assert_eq!(back.expect("16 bytes"), uuid);
+
+    PackedByteArray::from(&[1, 2, 3])
+        .to_uuid()
+        .expect_err("wrong length");
+}
+
+#[itest]
+fn variant_path_buf_conversions() {
+    roundtrip(PathBuf::from("saves/slot1.dat"));
+    roundtrip(PathBuf::from("res://scenes/main.tscn"));
+    roundtrip(PathBuf::from("user://settings.cfg"));
+    roundtrip(PathBuf::new());
+
+    let variant = PathBuf::from("icons/ui.svg").to_variant();
+    assert_eq!(variant.get_type(), VariantType::STRING);
+    assert_eq!(variant.to::<GString>(), gstr("icons/ui.svg"));
+    assert_eq!(
+        gstr("res://a/b.png").to_variant().to::<PathBuf>(),
+        PathBuf::from("res://a/b.png")
+    );
+
+    #[cfg(unix)]
+    {
+        roundtrip(PathBuf::from("/home/user/game.log"));
+
+        // Backslashes are ordinary file name characters on Unix.
+        roundtrip(PathBuf::from("dir\\file.txt"));
+    }
+
+    #[cfg(windows)]
+    {
+        let variant = PathBuf::from("C:\\Users\\user\\game.log").to_variant();
+        assert_eq!(variant.to::<GString>(), gstr("C:/Users/user/game.log"));
+        assert_eq!(
+            variant.to::<PathBuf>(),
+            PathBuf::from("C:/Users/user/game.log")
+        );
+    }
+
+    assert!(7.to_variant().try_to::<PathBuf>().is_err());
+}
+
+#[itest]
+fn variant_duration_conversions() {
+    roundtrip(Duration::ZERO);
+    roundtrip(Duration::from_secs(3));
+    roundtrip(Duration::from_millis(250));
+    roundtrip(Duration::from_micros(16_667));
+
+    let variant = Duration::from_millis(1500).to_variant();
+    assert_eq!(variant.get_type(), VariantType::FLOAT);
+    assert_eq!(variant.to::<f64>(), 1.5);
+
+    // Sub-nanosecond fractions are rounded.
+    let rounded = 1.0000000004f64.to_variant().to::<Duration>();
+    assert_eq!(rounded, Duration::from_secs(1));
+
+    // Negative, non-finite and out-of-range values are rejected.
+    assert!((-0.5f64).to_variant().try_to::<Duration>().is_err());
+    assert!(f64::NAN.to_variant().try_to::<Duration>().is_err());
+    assert!(f64::INFINITY.to_variant().try_to::<Duration>().is_err());
+    assert!(1e30f64.to_variant().try_to::<Duration>().is_err());
+
+    // Only FLOAT is accepted, not INT.
+    assert!(3.to_variant().try_to::<Duration>().is_err());
+}
+
+#[itest]
+fn variant_relaxed_conversions() {
+    // Expected results match GDScript's `int(x)`, `float(x)` and `bool(x)`.
+    let int_cases: &[(Variant, i64)] = &[
+        (42.to_variant(), 42),
+        (true.to_variant(), 1),
+        (false.to_variant(), 0),
+        (3.9.to_variant(), 3),
+        ((-3.9).to_variant(), -3),
+        ("42".to_variant(), 42),
+        ("-17".to_variant(), -17),
+        ("".to_variant(), 0),
+        ("abc".to_variant(), 0),
+    ];
+
+    for (variant, expected) in int_cases {
+        let actual = variant.try_to_relaxed::<i64>();
+        assert_eq!(actual.ok(), Some(*expected), "{variant:?} -> i64");
+    }
+
+    let float_cases: &[(Variant, f64)] = &[
+        (1.5.to_variant(), 1.5),
+        (42.to_variant(), 42.0),
+        (true.to_variant(), 1.0),
+        ("3.5".to_variant(), 3.5),
+        ("-0.25".to_variant(), -0.25),
+        ("".to_variant(), 0.0),
+        (f64::INFINITY.to_variant(), f64::INFINITY),
+    ];
+
+    for (variant, expected) in float_cases {
+        let actual = variant.try_to_relaxed::<f64>();
+        assert_eq!(actual.ok(), Some(*expected), "{variant:?} -> f64");
+    }
+
+    let bool_cases: &[(Variant, bool)] = &[
+        (0.to_variant(), false),
+        (5.to_variant(), true),
+        (0.0.to_variant(), false),
+        ((-0.5).to_variant(), true),
+    ];
+
+    for (variant, expected) in bool_cases {
+        let actual = variant.try_to_relaxed::<bool>();
+        assert_eq!(actual.ok(), Some(*expected), "{variant:?} -> bool");
+    }
+
+    // NaN is preserved for FLOAT -> f64.
+    let nan = f64::NAN.to_variant().try_to_relaxed::<f64>().unwrap();
+    assert!(nan.is_nan());
+
+    // Narrowing still fails if the value does not fit.
+    300.5.to_variant().try_to_relaxed::<u8>().unwrap_err();
+
+    // Strict conversion is unaffected.
+    42.to_variant().try_to::<f64>().unwrap_err();
+}
+
+#[itest]
+fn variant_convert() {
+    use VariantType as T;
+
+    // Expected results match GDScript's constructors, such as `int(x)` or `Vector2i(x)`.
+    let cases: &[(Variant, VariantType, Variant)] = &[
+        (3.9.to_variant(), T::INT, 3.to_variant()),
+        ((-3.9).to_variant(), T::INT, (-3).to_variant()),
+        (true.to_variant(), T::INT, 1.to_variant()),
+        (42.to_variant(), T::FLOAT, 42.0.to_variant()),
+        ("17".to_variant(), T::INT, 17.to_variant()),
+        ("2.5".to_variant(), T::FLOAT, 2.5.to_variant()),
+        (0.to_variant(), T::BOOL, false.to_variant()),
+        (
+            "abc".to_variant(),
+            T::STRING_NAME,
+            StringName::from("abc").to_variant(),
+        ),
+        (
+            Vector2::new(1.7, -2.2).to_variant(),
+            T::VECTOR2I,
+            Vector2i::new(1, -2).to_variant(),
+        ),
+        (
+            Vector2i::new(3, 4).to_variant(),
+            T::VECTOR2,
+            Vector2::new(3.0, 4.0).to_variant(),
+        ),
+        (
+            varray![1, 2].to_variant(),
+            T::PACKED_INT32_ARRAY,
+            PackedInt32Array::from(&[1, 2]).to_variant(),
+        ),
+        (
+            PackedInt32Array::from(&[5]).to_variant(),
+            T::ARRAY,
+            varray![5].to_variant(),
+        ),
+        // Same type: unchanged.
+        (7.to_variant(), T::INT, 7.to_variant()),
+    ];
+
+    for (variant, target, expected) in cases {
+        let converted = variant
+            .convert(*target)
+            .unwrap_or_else(|err| panic!("{variant:?} -> {target:?}: {err}"));
+
+        assert_eq!(converted.get_type(), *target, "{variant:?} -> {target:?}");
+        assert_eq!(converted, *expected, "{variant:?} -> {target:?}");
+    }
+
+    let bad_cases: &[(Variant, VariantType)] = &[
+        (dict! { "x": 1 }.to_variant(), T::VECTOR2),
+        (Vector3::ONE.to_variant(), T::INT),
+        (varray![1].to_variant(), T::DICTIONARY),
+        (f64::NAN.to_variant(), T::INT),
+        (1e300.to_variant(), T::INT),
+        (5.to_variant(), T::NIL),
+    ];
+
+    for (variant, target) in bad_cases {
+        variant
+            .convert(*target)
+            .expect_err(&format!("{variant:?} -> {target:?} should fail"));
+    }
+}
+
+#[itest]
+fn variant_can_convert_to() {
+    use VariantType as T;
+
+    let cases: &[(Variant, VariantType, bool)] = &[
+        // Identity.
+        (42.to_variant(), T::INT, true),
+        (dict! {}.to_variant(), T::DICTIONARY, true),
+        // Numeric coercions, both directions.
+        (42.to_variant(), T::FLOAT, true),
+        (4.2.to_variant(), T::INT, true),
+        (true.to_variant(), T::INT, true),
+        (42.to_variant(), T::BOOL, true),
+        // Strings, regardless of content.
+        (42.to_variant(), T::STRING, true),
+        ("42".to_variant(), T::INT, true),
+        ("abc".to_variant(), T::INT, true),
+        ("abc".to_variant(), T::STRING_NAME, true),
+        (StringName::from("abc").to_variant(), T::STRING, true),
+        // Vectors and containers.
+        (Vector2i::new(1, 2).to_variant(), T::VECTOR2, true),
+        (Vector2::new(1.0, 2.0).to_variant(), T::VECTOR2I, true),
+        (varray![1, 2].to_variant(), T::PACKED_INT32_ARRAY, true),
+        (PackedInt32Array::new().to_variant(), T::ARRAY, true),
+        // Incompatible.
+        (dict! {}.to_variant(), T::VECTOR2, false),
+        (42.to_variant(), T::VECTOR2, false),
+        (Vector2::ZERO.to_variant(), T::VECTOR3, false),
+        (varray![].to_variant(), T::DICTIONARY, false),
+        (Color::RED.to_variant(), T::INT, false),
+        // Only nil converts to nil, in line with convert().
+        (Variant::nil(), T::NIL, true),
+        (42.to_variant(), T::NIL, false),
+    ];
+
+    for (variant, target, expected) in cases {
+        assert_eq!(
+            variant.can_convert_to(*target),
+            *expected,
+            "{:?} -> {target:?}",
+            variant.get_type()
+        );
+    }
+
+    // Consistent with try_to_relaxed() for valid values.
+    assert!(42.to_variant().try_to_relaxed::<f64>().is_ok());
+    assert!(dict! {}.to_variant().try_to_relaxed::<Vector2>().is_err());
+    assert!(42.to_variant().convert(T::NIL).is_err());
+}
+
+#[itest]
+fn variant_relaxed_bad_conversions() {
+    let i64_errors = [
+        Variant::nil(),
+        f64::NAN.to_variant(),
+        f64::INFINITY.to_variant(),
+        f64::NEG_INFINITY.to_variant(),
+        1e300.to_variant(),
+        9.3e18.to_variant(),
+        Vector2::new(1.0, 2.0).to_variant(),
+        varray![1].to_variant(),
+    ];
+
+    for variant in i64_errors {
+        variant
+            .try_to_relaxed::<i64>()
+            .expect_err(&format!("{variant:?} should not convert to i64"));
+    }
+
+    let f64_errors = [
+        Variant::nil(),
+        Vector3::ZERO.to_variant(),
+        dict! { "a": 1 }.to_variant(),
+    ];
+
+    for variant in f64_errors {
+        variant
+            .try_to_relaxed::<f64>()
+            .expect_err(&format!("{variant:?} should not convert to f64"));
+    }
+}
+
 #[itest]
 fn variant_dead_object_conversions() {
     let obj = Node::new_alloc();
@@ -239,6 +797,63 @@ fn variant_get_type() {
     assert_eq!(variant.get_type(), VariantType::BASIS)
 }
 
+#[itest]
+fn variant_type_name() {
+    assert_eq!(Variant::nil().type_name(), "Nil");
+    assert_eq!(74i32.to_variant().type_name(), "int");
+    assert_eq!(gstr("hello").to_variant().type_name(), "String");
+    assert_eq!(Vector2::ZERO.to_variant().type_name(), "Vector2");
+    assert_eq!(TEST_BASIS.to_variant().type_name(), "Basis");
+    assert_eq!(
+        PackedByteArray::new().to_variant().type_name(),
+        "PackedByteArray"
+    );
+
+    // Names must match Godot's for all types.
+    for ord in 0..VariantType::MAX.ord() {
+        let variant_type = VariantType::from_ord(ord);
+        let expected = godot::global::type_string(ord as i64);
+
+        assert_eq!(
+            GString::from(variant_type.type_name()),
+            expected,
+            "name mismatch for {variant_type:?}"
+        );
+    }
+
+    assert_eq!(VariantType::MAX.type_name(), "");
+}
+
+#[itest]
+fn variant_type_from_godot_name() {
+    for ord in 0..VariantType::MAX.ord() {
+        let variant_type = VariantType::from_ord(ord);
+        let name = variant_type.type_name();
+
+        assert_eq!(
+            VariantType::from_godot_name(name),
+            Some(variant_type),
+            "round-trip failed for {name}"
+        );
+    }
+
+    assert_eq!(
+        VariantType::from_godot_name("AABB"),
+        Some(VariantType::AABB)
+    );
+    assert_eq!(VariantType::from_godot_name("RID"), Some(VariantType::RID));
+    assert_eq!(
+        VariantType::from_godot_name("String"),
+        Some(VariantType::STRING)
+    );
+
+    // Rust names and other spellings are not recognized.
+    assert_eq!(VariantType::from_godot_name("GString"), None);
+    assert_eq!(VariantType::from_godot_name("Aabb"), None);
+    assert_eq!(VariantType::from_godot_name("vector2"), None);
+    assert_eq!(VariantType::from_godot_name(""), None);
+}
+
 #[itest]
 fn variant_object_id() {
     let variant = Variant::nil();
@@ -294,13 +909,343 @@ fn variant_equal() {
     assert_ne!(77.to_variant(), Variant::nil());
     assert_ne!(77.to_variant(), 78.to_variant());
 
-    //equal(77, 77.0, false)
+    // Godot's == compares numbers by value, strings by content and containers by elements.
+    equal(77, 77.0, true);
+    equal(77, 77.5, false);
+    equal(gstr("String"), StringName::from("String"), true);
+    equal(varray![1, "two", 3.0], varray![1, "two", 3.0], true);
+    equal(varray![1, 2], varray![1, 2, 3], false);
+    equal(dict! { "a": 1, "b": 2 }, dict! { "b": 2, "a": 1 }, true);
+    equal(dict! { "a": 1 }, dict! { "a": 2 }, false);
+    equal((), (), true);
+    equal((), 0, false);
+    equal((), false, false);
+
+    // Incompatible types are unequal (GDScript would report an error instead).
     equal(Vector3::new(1.0, 2.0, 3.0), Vector2::new(1.0, 2.0), false);
     equal(1, true, false);
     equal(false, 0, false);
     equal(gstr("String"), 33, false);
 }
 
+#[itest]
+fn variant_size() {
+    assert_eq!(varray![1, "two", 3.0].to_variant().size(), Some(3));
+    assert_eq!(VariantArray::new().to_variant().size(), Some(0));
+    assert_eq!(array![1, 2].to_variant().size(), Some(2)); // Typed.
+
+    assert_eq!(dict! { "a": 1, "b": 2 }.to_variant().size(), Some(2));
+    assert_eq!(Dictionary::new().to_variant().size(), Some(0));
+
+    assert_eq!(
+        PackedByteArray::from(&[1, 2, 3, 4]).to_variant().size(),
+        Some(4)
+    );
+    assert_eq!(PackedByteArray::new().to_variant().size(), Some(0));
+    assert_eq!(PackedInt32Array::from(&[7]).to_variant().size(), Some(1));
+
+    assert_eq!(42.to_variant().size(), None);
+    assert_eq!("text".to_variant().size(), None);
+    assert_eq!(Vector3::ONE.to_variant().size(), None);
+    assert_eq!(Variant::nil().size(), None);
+}
+
+#[itest]
+fn variant_iter() {
+    fn collect(variant: Variant) -> Vec<Variant> {
+        variant.iter().expect("variant is iterable").collect()
+    }
+
+    // Integer: counts from 0, like `for i in 3`.
+    let ints = collect(3.to_variant());
+    assert_eq!(ints, [0.to_variant(), 1.to_variant(), 2.to_variant()]);
+    assert!(collect(0.to_variant()).is_empty());
+    assert!(collect((-2).to_variant()).is_empty());
+
+    // Integer vector: range from x to y.
+    let range = collect(Vector2i::new(2, 5).to_variant());
+    assert_eq!(range, [2.to_variant(), 3.to_variant(), 4.to_variant()]);
+
+    // Arrays, typed or not.
+    let elements = collect(varray![1, "two", 3.5].to_variant());
+    assert_eq!(
+        elements,
+        varray![1, "two", 3.5].iter_shared().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        collect(array![7, 8].to_variant()),
+        [7.to_variant(), 8.to_variant()]
+    );
+    assert!(collect(VariantArray::new().to_variant()).is_empty());
+
+    // Dictionaries yield keys.
+    let keys = collect(dict! { "a": 1, "b": 2 }.to_variant());
+    assert_eq!(keys, ["a".to_variant(), "b".to_variant()]);
+
+    // Strings yield characters.
+    let chars = collect("hé".to_variant());
+    assert_eq!(chars, ["h".to_variant(), "é".to_variant()]);
+
+    // Exhausted iterator stays exhausted.
+    let mut iter = 1.to_variant().iter().unwrap();
+    assert_eq!(iter.next(), Some(0.to_variant()));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    // Non-iterable variants fail upfront.
+    for variant in [Variant::nil(), true.to_variant(), Color::RED.to_variant()] {
+        let err = variant.iter().err().expect("not iterable");
+        assert!(err.to_string().contains("is not iterable"), "{err}");
+    }
+}
+
+#[itest]
+fn variant_diff() {
+    let expected = dict! {
+        "name": "player",
+        "stats": dict! { "hp": 10, "tags": varray!["a", "b"] },
+    }
+    .to_variant();
+
+    let diff = |actual: Dictionary| actual.to_variant().diff(&expected).map(|d| d.to_string());
+
+    assert_eq!(expected.diff(&expected), None);
+    assert_eq!(42.to_variant().diff(&42.to_variant()), None);
+    assert_eq!(
+        42.to_variant()
+            .diff(&43.to_variant())
+            .map(|d| d.to_string()),
+        Some("<root>: 42 != 43".to_string())
+    );
+
+    // Nested value mismatch.
+    let actual = dict! {
+        "name": "player",
+        "stats": dict! { "hp": 7, "tags": varray!["a", "b"] },
+    };
+    assert_eq!(diff(actual).as_deref(), Some(r#"["stats"]["hp"]: 7 != 10"#));
+
+    // Differing types at a path.
+    let actual = dict! {
+        "name": "player",
+        "stats": dict! { "hp": 10, "tags": varray!["a", 2] },
+    };
+    assert_eq!(
+        diff(actual).as_deref(),
+        Some(r#"["stats"]["tags"][1]: type INT != STRING (2 vs "b")"#)
+    );
+
+    // Arrays of different length.
+    let actual = dict! {
+        "name": "player",
+        "stats": dict! { "hp": 10, "tags": varray!["a", "b", "c"] },
+    };
+    assert_eq!(
+        diff(actual).as_deref(),
+        Some(r#"["stats"]["tags"]: array length 3 != 2"#)
+    );
+
+    // Missing and extra keys.
+    let actual = dict! { "name": "player" };
+    let missing = diff(actual).expect("missing key is a difference");
+    assert!(
+        missing.starts_with(r#"["stats"]: only in right"#),
+        "{missing}"
+    );
+
+    let actual = dict! {
+        "name": "player",
+        "stats": dict! { "hp": 10, "tags": varray!["a", "b"], "extra": true },
+    };
+    assert_eq!(
+        diff(actual).as_deref(),
+        Some(r#"["stats"]["extra"]: only in left (true)"#)
+    );
+}
+
+#[itest]
+fn variant_recursive_equal() {
+    // a = [1, a]
+    let mut a = varray![1];
+    a.push(&a.to_variant());
+
+    // b = [1, b]
+    let mut b = varray![1];
+    b.push(&b.to_variant());
+
+    // c = [1, [1, c]] -- differently shaped cycle, but indistinguishable by walking.
+    let mut c = varray![1];
+    let mut c_inner = varray![1];
+    c_inner.push(&c.to_variant());
+    c.push(&c_inner.to_variant());
+
+    // d = [2, d]
+    let mut d = varray![2];
+    d.push(&d.to_variant());
+
+    let (a_var, b_var, c_var, d_var) = (
+        a.to_variant(),
+        b.to_variant(),
+        c.to_variant(),
+        d.to_variant(),
+    );
+    assert!(a_var.recursive_equal(&a_var));
+    assert!(a_var.recursive_equal(&b_var));
+    assert!(b_var.recursive_equal(&a_var));
+    assert!(a_var.recursive_equal(&c_var));
+    assert!(!a_var.recursive_equal(&d_var));
+
+    // Cyclic dictionary.
+    let mut dict1 = dict! { "name": "node" };
+    dict1.set("self", dict1.clone());
+    let mut dict2 = dict! { "name": "node" };
+    dict2.set("self", dict2.clone());
+    let mut dict3 = dict! { "name": "other" };
+    dict3.set("self", dict3.clone());
+
+    assert!(dict1.to_variant().recursive_equal(&dict2.to_variant()));
+    assert!(!dict1.to_variant().recursive_equal(&dict3.to_variant()));
+
+    // Break the cycles, otherwise the containers leak.
+    a.clear();
+    b.clear();
+    c.clear();
+    d.clear();
+    dict1.clear();
+    dict2.clear();
+    dict3.clear();
+
+    // Shared substructure is equal to a copied one.
+    let shared = varray![1, "two", 3.0];
+    let with_shared = varray![shared.clone(), shared.clone()].to_variant();
+    let with_copies = varray![varray![1, "two", 3.0], varray![1, "two", 3.0]].to_variant();
+    assert!(with_shared.recursive_equal(&with_copies));
+    assert!(with_copies.recursive_equal(&with_shared));
+
+    // Non-cyclic values behave like ==.
+    assert!(42.to_variant().recursive_equal(&42.to_variant()));
+    assert!(!42.to_variant().recursive_equal(&42.0.to_variant()));
+    assert!(!varray![1, 2]
+        .to_variant()
+        .recursive_equal(&varray![1].to_variant()));
+    assert!(!dict! { "a": 1 }
+        .to_variant()
+        .recursive_equal(&dict! { "b": 1 }.to_variant()));
+}
+
+#[itest]
+fn variant_bytes_roundtrip() {
+    let values = [
+        Variant::nil(),
+        true.to_variant(),
+        (-123456789i64).to_variant(),
+        2.5.to_variant(),
+        "hello".to_variant(),
+        Vector2::new(1.0, -2.0).to_variant(),
+        TEST_BASIS.to_variant(),
+        PackedInt32Array::from(&[1, 2, 3]).to_variant(),
+        varray![1, "two", Vector3::ONE].to_variant(),
+        dict! {
+            "name": "player",
+            "stats": dict! { "hp": 100, "items": varray!["sword", "shield"] },
+            7: Color::from_rgba(1.0, 0.5, 0.0, 1.0),
+        }
+        .to_variant(),
+    ];
+
+    for value in values {
+        let bytes = value.to_bytes();
+        let back = Variant::from_bytes(&bytes).expect("decoding valid bytes should succeed");
+
+        assert_eq!(back, value);
+        assert_eq!(back.get_type(), value.get_type());
+    }
+}
+
+#[itest]
+fn variant_bytes_with_objects() {
+    let node = Node::new_alloc();
+    let bytes = node.to_variant().to_bytes_with_objects();
+    let back = Variant::from_bytes_with_objects(&bytes).expect("decoding object should succeed");
+
+    // Decoding creates a new object of the same class.
+    let decoded = back.to::<Gd<Node>>();
+    assert_ne!(decoded.instance_id(), node.instance_id());
+
+    decoded.free();
+    node.free();
+}
+
+#[itest]
+fn variant_bytes_nil() {
+    let nil = Variant::nil();
+    let back = Variant::from_bytes(&nil.to_bytes()).expect("encoded nil should decode");
+    assert!(back.is_nil());
+
+    let back = Variant::from_bytes_with_objects(&nil.to_bytes_with_objects())
+        .expect("encoded nil should decode with objects");
+    assert!(back.is_nil());
+
+    // Null object, as returned by Godot for an unset object property.
+    let node = Node::new_alloc();
+    let null_object = node.get("owner");
+    assert!(null_object.is_nil());
+
+    let back = Variant::from_bytes(&null_object.to_bytes()).expect("null object should decode");
+    assert!(back.is_nil());
+
+    let back = Variant::from_bytes_with_objects(&null_object.to_bytes_with_objects())
+        .expect("null object should decode with objects");
+    assert!(back.is_nil());
+
+    node.free();
+}
+
+#[itest]
+fn variant_bytes_malformed() {
+    let mut result = Ok(Variant::nil());
+    suppress_godot_print(|| {
+        result = Variant::from_bytes(&PackedByteArray::from(&[0xff, 0xff, 0xff, 0xff, 1]));
+    });
+    result.expect_err("unknown type header should fail");
+
+    let mut result = Ok(Variant::nil());
+    suppress_godot_print(|| {
+        result = Variant::from_bytes(&PackedByteArray::from(&[1, 0]));
+    });
+    result.expect_err("truncated buffer should fail");
+}
+
+#[itest]
+fn variant_approx_eq() {
+    let approx = |lhs: Variant, rhs: Variant| lhs.approx_eq(&rhs);
+
+    assert!(approx(0.3.to_variant(), (0.1 + 0.2).to_variant()));
+    assert!(approx(1.to_variant(), 1.000001.to_variant()));
+    assert!(!approx(1.to_variant(), 1.1.to_variant()));
+
+    let a = Vector2::new(0.1 + 0.2, 1.0);
+    let b = Vector2::new(0.3, 1.0);
+    assert_ne!(a.to_variant(), b.to_variant());
+    assert!(approx(a.to_variant(), b.to_variant()));
+    assert!(!approx(a.to_variant(), Vector2::new(0.4, 1.0).to_variant()));
+
+    let color = Color::from_rgb(0.1 + 0.2, 0.5, 0.5);
+    assert!(approx(
+        color.to_variant(),
+        Color::from_rgb(0.3, 0.5, 0.5).to_variant()
+    ));
+
+    // Other types fall back to ==.
+    assert!(approx("hello".to_variant(), "hello".to_variant()));
+    assert!(!approx("hello".to_variant(), "world".to_variant()));
+    assert!(approx(Variant::nil(), Variant::nil()));
+    assert!(!approx(
+        Vector2::ZERO.to_variant(),
+        Vector3::ZERO.to_variant()
+    ));
+}
+
 #[itest]
 fn variant_call() {
     let node2d = Node2D::new_alloc();
@@ -352,6 +1297,120 @@ fn variant_call() {
     node2d.free();
 }
 
+#[itest]
+fn variant_try_call() {
+    let node2d = Node2D::new_alloc();
+    let variant = Variant::from(node2d.clone());
+
+    // Object
+    let position = Vector2::new(4.0, 5.0);
+    let result = variant
+        .try_call("set_position", &[position.to_variant()])
+        .expect("`set_position` should succeed");
+    assert!(result.is_nil());
+
+    let result = variant
+        .try_call("get_position", &[])
+        .expect("`get_position` should succeed");
+    assert_eq!(result, position.to_variant());
+
+    // Built-in
+    let vector = Vector2::new(5.0, 3.0);
+    let result = vector
+        .to_variant()
+        .try_call("dot", &[Vector2::new(1.0, -1.0).to_variant()])
+        .expect("`dot` should succeed");
+    assert_eq!(result, 2.0.to_variant());
+
+    // Dynamic checks are only available in Debug builds.
+    if !runs_release() {
+        let err = variant
+            .try_call("gut_position", &[])
+            .expect_err("non-existent method should fail");
+        assert_eq!(err.class_name(), Some("Object"));
+        assert_eq!(err.method_name(), "call");
+
+        variant
+            .try_call("set_position", &[])
+            .expect_err("bad signature should fail");
+
+        let err = Variant::nil()
+            .try_call("to_string", &[])
+            .expect_err("call on Nil should fail");
+        assert_eq!(err.class_name(), Some("Nil"));
+
+        Variant::from(77)
+            .try_call("to_string", &[])
+            .expect_err("call on int should fail");
+    }
+
+    node2d.free();
+}
+
+#[itest]
+fn variant_get_indexed() {
+    let vector = Vector2::new(4.0, 5.0).to_variant();
+    assert_eq!(vector.get_indexed("x").unwrap(), 4.0.to_variant());
+    assert_eq!(vector.get_indexed(":y").unwrap(), 5.0.to_variant());
+
+    let rect = Rect2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)).to_variant();
+    assert_eq!(rect.get_indexed("size:y").unwrap(), 4.0.to_variant());
+
+    let mut node = Node2D::new_alloc();
+    node.set_position(Vector2::new(-3.0, 8.0));
+    let object = node.to_variant();
+    assert_eq!(
+        object.get_indexed("position:x").unwrap(),
+        (-3.0).to_variant()
+    );
+    assert_eq!(
+        object.get_indexed("position").unwrap(),
+        Vector2::new(-3.0, 8.0).to_variant()
+    );
+
+    // Errors.
+    let err = vector.get_indexed("z").expect_err("Vector2 has no `z`");
+    assert!(err.to_string().contains("`z` of Vector2"), "{err}");
+    assert!(object.get_indexed("position:x:y").is_err());
+    assert!(object.get_indexed("no_such_property").is_err());
+    assert!(vector.get_indexed("").is_err());
+    assert!(Variant::nil().get_indexed("x").is_err());
+
+    node.free();
+}
+
+#[itest]
+fn variant_set_indexed() {
+    let mut vector = Vector2::new(4.0, 5.0).to_variant();
+    vector.set_indexed("x", &1.5.to_variant()).unwrap();
+    assert_eq!(vector, Vector2::new(1.5, 5.0).to_variant());
+
+    // Nested built-in values are written back.
+    let mut rect = Rect2::new(Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)).to_variant();
+    rect.set_indexed("size:x", &10.0.to_variant()).unwrap();
+    assert_eq!(
+        rect,
+        Rect2::new(Vector2::new(1.0, 2.0), Vector2::new(10.0, 4.0)).to_variant()
+    );
+
+    let node = Node2D::new_alloc();
+    let mut object = node.to_variant();
+    object.set_indexed("position:y", &7.0.to_variant()).unwrap();
+    assert_eq!(node.get_position(), Vector2::new(0.0, 7.0));
+
+    // Errors leave the value unchanged.
+    let err = vector
+        .set_indexed("x", &"text".to_variant())
+        .expect_err("cannot assign String to float member");
+    assert!(err.to_string().contains("cannot set member `x`"), "{err}");
+    assert!(vector.set_indexed("w", &1.0.to_variant()).is_err());
+    assert!(rect.set_indexed("size:z", &1.0.to_variant()).is_err());
+    assert!(vector.set_indexed("", &1.0.to_variant()).is_err());
+    assert_eq!(vector, Vector2::new(1.5, 5.0).to_variant());
+
+    node.free();
+}
+
 #[rustfmt::skip]
 #[itest]
 fn variant_evaluate() {
@@ -503,6 +1562,22 @@ fn variant_stringify() {
         dict! { "KEY": 50 }.to_variant().stringify(),
         gstr("{ \"KEY\": 50 }")
     );
+
+    // Strings are only quoted inside containers, like GDScript's str().
+    assert_eq!(1.5.to_variant().stringify(), gstr("1.5"));
+    assert_eq!(
+        varray!["quoted", 2.5].to_variant().stringify(),
+        gstr("[\"quoted\", 2.5]")
+    );
+
+    let nested = dict! {
+        "list": varray![1, dict! { "inner": "text" }],
+        "empty": varray![],
+    };
+    assert_eq!(
+        nested.to_variant().stringify(),
+        gstr("{ \"list\": [1, { \"inner\": \"text\" }], \"empty\": [] }")
+    );
 }
 
 #[itest]
@@ -517,6 +1592,78 @@ fn variant_booleanize() {
     assert!(!0.to_variant().booleanize());
     assert!(!Variant::nil().booleanize());
     assert!(!gstr("").to_variant().booleanize());
+
+    // Same rules as GDScript's `if value:`, i.e. `Variant::booleanize()` in Godot -- truthy unless the value is the type's default.
+    let node = Node::new_alloc();
+    let table: [(Variant, bool); 20] = [
+        (true.to_variant(), true),
+        (false.to_variant(), false),
+        ((-1).to_variant(), true),
+        (0.0.to_variant(), false),
+        ((-0.0).to_variant(), false),
+        (0.25.to_variant(), true),
+        (f64::NAN.to_variant(), true),
+        (sname("").to_variant(), false),
+        (sname("name").to_variant(), true),
+        (NodePath::default().to_variant(), false),
+        (NodePath::from("..").to_variant(), true),
+        (Vector2::ZERO.to_variant(), false),
+        (Vector2::new(0.0, 1.0).to_variant(), true),
+        // Godot's default color is opaque black, so transparent black is truthy.
+        (Color::BLACK.to_variant(), false),
+        (Color::from_rgba(0.0, 0.0, 0.0, 0.0).to_variant(), true),
+        (PackedInt32Array::new().to_variant(), false),
+        (PackedInt32Array::from(&[0]).to_variant(), true),
+        (varray![Variant::nil()].to_variant(), true),
+        (node.to_variant(), true),
+        (Variant::nil(), false),
+    ];
+
+    for (variant, expected) in table {
+        assert_eq!(variant.booleanize(), expected, "booleanize({variant:?})");
+    }
+
+    // Unlike `booleanize()`, strict conversion to `bool` only accepts `BOOL` variants.
+    assert!(1.to_variant().try_to::<bool>().is_err());
+
+    node.free();
+}
+
+#[itest]
+fn variant_duplicate() {
+    let original = varray![1, varray![2]].to_variant();
+
+    // Shallow: outer container is independent, nested one is shared.
+    let shallow = original.duplicate_shallow();
+    let mut outer = shallow.to::<VariantArray>();
+    outer.push(&3.to_variant());
+    let mut nested = outer.at(1).to::<VariantArray>();
+    nested.push(&4.to_variant());
+    assert_eq!(original, varray![1, varray![2, 4]].to_variant());
+
+    // Deep: nothing is shared.
+    let original = varray![1, varray![2]].to_variant();
+    let deep = original.duplicate_deep();
+    let mut outer = deep.to::<VariantArray>();
+    outer.push(&3.to_variant());
+    let mut nested = outer.at(1).to::<VariantArray>();
+    nested.push(&4.to_variant());
+    assert_eq!(original, varray![1, varray![2]].to_variant());
+    assert_eq!(deep, varray![1, varray![2, 4], 3].to_variant());
+
+    let dict = dict! { "key": dict! { "inner": 1 } }.to_variant();
+    let copy = dict.duplicate_deep();
+    copy.to::<Dictionary>()
+        .at("key")
+        .to::<Dictionary>()
+        .set("inner", 2);
+    assert_eq!(dict, dict! { "key": dict! { "inner": 1 } }.to_variant());
+
+    // Non-container types behave like clone().
+    for variant in [Variant::nil(), 7.to_variant(), gstr("text").to_variant()] {
+        assert_eq!(variant.duplicate_shallow(), variant);
+        assert_eq!(variant.duplicate_deep(), variant);
+    }
 }
 
 #[itest]
@@ -547,6 +1694,32 @@ fn variant_hash() {
     assert_ne!(dict! { 0: dict! { 0: 0 } }, dict! { 0: dict! { 0: 1 } });
 }
 
+#[itest]
+fn variant_hash_equality() {
+    let equal_pairs = [
+        (gstr("godot").to_variant(), gstr("godot").to_variant()),
+        (42.to_variant(), 42.to_variant()),
+        (
+            Vector2::new(1.0, 2.0).to_variant(),
+            Vector2::new(1.0, 2.0).to_variant(),
+        ),
+        (
+            varray![1, "two"].to_variant(),
+            varray![1, "two"].to_variant(),
+        ),
+    ];
+    for (a, b) in equal_pairs {
+        assert_eq!(a, b);
+        assert_eq!(a.hash(), b.hash());
+    }
+
+    assert_ne!(
+        gstr("godot").to_variant().hash(),
+        gstr("rust").to_variant().hash()
+    );
+    assert_ne!(1.to_variant().hash(), 2.to_variant().hash());
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn truncate_bad<T>(original_value: i64)
@@ -5,13 +5,15 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::HashMap;
+
 use godot::builtin::{
-    array, dict, Array, Dictionary, GString, NodePath, StringName, Variant, VariantArray, Vector2,
-    Vector2Axis,
+    array, dict, varray, Array, Dictionary, GString, NodePath, StringName, Variant, VariantArray,
+    VariantType, Vector2, Vector2Axis, Vector3,
 };
 use godot::classes::{Node, Resource};
 use godot::meta::error::ConvertError;
-use godot::meta::{AsArg, CowArg, FromGodot, GodotConvert, ToGodot};
+use godot::meta::{AsArg, CowArg, FromGodot, GodotConvert, TaggedResult, ToGodot};
 use godot::obj::{Gd, NewAlloc};
 
 use crate::framework::itest;
@@ -89,6 +91,26 @@ fn error_maintains_value() {
     assert_eq!(format!("{value:?}"), format!("{:?}", err.value().unwrap()));
 }
 
+#[itest]
+fn error_exposes_variant_types() {
+    let err = "hello".to_variant().try_to::<Vector3>().unwrap_err();
+    assert_eq!(err.expected_type(), Some(VariantType::VECTOR3));
+    assert_eq!(err.actual_type(), Some(VariantType::STRING));
+
+    let err = Variant::nil().try_to::<GString>().unwrap_err();
+    assert_eq!(err.expected_type(), Some(VariantType::STRING));
+    assert_eq!(err.actual_type(), Some(VariantType::NIL));
+
+    // Errors not caused by a type mismatch carry no type information.
+    let err = 300.to_variant().try_to::<u8>().unwrap_err();
+    assert_eq!(err.expected_type(), None);
+    assert_eq!(err.actual_type(), None);
+
+    let err = ConvertError::new("custom");
+    assert_eq!(err.expected_type(), None);
+    assert_eq!(err.actual_type(), None);
+}
+
 // Manual implementation of `GodotConvert` and related traits to ensure conversion works.
 #[derive(PartialEq, Debug)]
 struct ConvertedStruct {
@@ -278,6 +300,70 @@ fn array_to_vec() {
     assert!(to.is_err());
 }
 
+#[itest]
+fn untyped_array_to_vec() {
+    let from = varray![Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)];
+    let to = from.to_variant().to::<Vec<Vector2>>();
+    assert_eq!(to, vec![Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)]);
+
+    let empty = varray![].to_variant().to::<Vec<Vector2>>();
+    assert!(empty.is_empty());
+
+    // Bad element: error names its index.
+    let from = varray![Vector2::ZERO, Vector2::ONE, "not a vector"];
+    let err = from
+        .to_variant()
+        .try_to::<Vec<Vector2>>()
+        .expect_err("String element must fail");
+    assert!(
+        err.to_string().contains("index 2"),
+        "error message should contain index: {err}"
+    );
+    assert_eq!(err.value(), Some(&from.to_variant()));
+}
+
+#[itest]
+fn tagged_result_roundtrip() {
+    let ok: Result<i64, GString> = Ok(42);
+    let variant = TaggedResult(ok.clone()).to_variant();
+    assert_eq!(variant.to::<Dictionary>(), dict! { "ok": 42 });
+    assert_eq!(variant.to::<TaggedResult<i64, GString>>().0, ok);
+
+    let err: Result<i64, GString> = Err(GString::from("failed"));
+    let variant = TaggedResult::from(err.clone()).to_variant();
+    assert_eq!(variant.to::<Dictionary>(), dict! { "err": "failed" });
+    assert_eq!(variant.to::<TaggedResult<i64, GString>>().0, err);
+
+    let nested = TaggedResult::<Vector2, ()>(Ok(Vector2::new(1.0, 2.0)));
+    assert_eq!(
+        nested.to_variant().to::<TaggedResult<Vector2, ()>>(),
+        nested
+    );
+}
+
+#[itest]
+fn tagged_result_from_bad_dictionary() {
+    let malformed = [
+        dict! {},
+        dict! { "value": 1 },
+        dict! { "ok": 1, "err": "both" },
+        dict! { "ok": 1, "extra": 2 },
+    ];
+    for dict in malformed {
+        let result = dict.to_variant().try_to::<TaggedResult<i64, GString>>();
+        assert!(result.is_err(), "{dict:?} must not convert to Result");
+    }
+
+    // Tag present, but value of wrong type.
+    let result = dict! { "ok": "text" }
+        .to_variant()
+        .try_to::<TaggedResult<i64, GString>>();
+    assert!(result.is_err());
+
+    let result = 5.to_variant().try_to::<TaggedResult<i64, GString>>();
+    assert!(result.is_err());
+}
+
 #[itest]
 fn rust_array_to_array() {
     let from = [1, 2, 3];
@@ -310,6 +396,50 @@ fn array_to_rust_array() {
     assert!(to.is_err());
 }
 
+#[itest]
+fn rust_array_roundtrip() {
+    let from: [i64; 4] = [1, -2, i64::MAX, i64::MIN];
+    let variant = from.to_variant();
+    assert_eq!(
+        variant.to::<Array<i64>>(),
+        array![1, -2, i64::MAX, i64::MIN]
+    );
+    assert_eq!(variant.to::<[i64; 4]>(), from);
+
+    let empty: [i64; 0] = [];
+    assert_eq!(empty.to_variant().to::<[i64; 0]>(), empty);
+}
+
+#[itest]
+fn array_to_rust_array_bad_length() {
+    let from = array![1, 2, 3];
+
+    let err = from.to_variant().try_to::<[i64; 4]>().unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Array<T> of length 3 cannot be stored in [T; 4] Rust array"),
+        "unexpected error: {err}"
+    );
+
+    from.to_variant().try_to::<[i64; 2]>().unwrap_err();
+    from.to_variant().try_to::<[i64; 0]>().unwrap_err();
+}
+
+#[itest]
+fn array_to_rust_array_bad_element() {
+    // Untyped array with an element that is not an int.
+    let from = varray![1, "two", 3, 4];
+    from.to_variant()
+        .try_to::<[i64; 4]>()
+        .expect_err("untyped array with mixed elements should not convert to [i64; 4]");
+
+    // Typed array of different element type.
+    let from = array![1.0, 2.0, 3.0, 4.0];
+    from.to_variant()
+        .try_to::<[i64; 4]>()
+        .expect_err("Array<f64> should not convert to [i64; 4]");
+}
+
 #[itest]
 fn slice_to_array() {
     let from = &[1, 2, 3];
@@ -326,6 +456,78 @@ fn slice_to_array() {
     assert!(to.is_err());
 }
 
+#[itest]
+fn hash_map_roundtrip() {
+    let map = HashMap::from([
+        (GString::from("health"), 100),
+        (GString::from("mana"), -5),
+        (GString::from(""), 0),
+    ]);
+
+    let dict = map.to_godot();
+    assert_eq!(dict.len(), 3);
+    assert_eq!(dict.get("health"), Some(100.to_variant()));
+    assert_eq!(dict.get("mana"), Some((-5).to_variant()));
+
+    let back = map.to_variant().to::<HashMap<GString, i64>>();
+    assert_eq!(back, map);
+
+    let empty = HashMap::<GString, i64>::new();
+    assert_eq!(empty.to_variant().to::<HashMap<GString, i64>>(), empty);
+}
+
+#[itest]
+fn hash_map_from_bad_dictionary() {
+    let err = dict! { "a": 1, "b": "text" }
+        .to_variant()
+        .try_to::<HashMap<GString, i64>>()
+        .expect_err("value of wrong type");
+    assert!(err.to_string().contains("value for key b"), "{err}");
+
+    let err = dict! { "a": 1, 7: 2 }
+        .to_variant()
+        .try_to::<HashMap<GString, i64>>()
+        .expect_err("key of wrong type");
+    assert!(err.to_string().contains("key 7"), "{err}");
+
+    let result = varray![1, 2].to_variant().try_to::<HashMap<GString, i64>>();
+    assert!(result.is_err());
+}
+
+#[itest]
+fn tuple_roundtrip() {
+    let pair = (42, GString::from("answer"));
+    let array = pair.to_godot();
+    assert_eq!(array, varray![42, "answer"]);
+    assert_eq!(pair.to_variant().to::<(i64, GString)>(), pair);
+
+    let quad = (true, 1.5, Vector2::new(1.0, 2.0), StringName::from("name"));
+    let back = quad.to_variant().to::<(bool, f64, Vector2, StringName)>();
+    assert_eq!(back, quad);
+
+    let single = (Vector3::new(1.0, 2.0, 3.0),);
+    assert_eq!(single.to_variant().to::<(Vector3,)>(), single);
+}
+
+#[itest]
+fn tuple_from_bad_array() {
+    let err = varray![1, 2, 3]
+        .to_variant()
+        .try_to::<(i64, i64)>()
+        .expect_err("arity mismatch");
+    assert!(err.to_string().contains("length 3"), "{err}");
+    assert!(err.to_string().contains("arity 2"), "{err}");
+
+    let err = varray![1, "two", 3.0, false]
+        .to_variant()
+        .try_to::<(i64, i64, f64, bool)>()
+        .expect_err("element type mismatch");
+    assert!(err.to_string().contains("index 1"), "{err}");
+
+    let result = 1.to_variant().try_to::<(i64,)>();
+    assert!(result.is_err());
+}
+
 fn as_gstr_arg<'a, T: 'a + AsArg<GString>>(t: T) -> CowArg<'a, GString> {
     t.into_arg()
 }
@@ -365,3 +567,35 @@ fn strings_as_arg() {
     assert_eq!(as_npath_arg(gstring.arg()), CowArg::Owned(npath.clone()));
     assert_eq!(as_npath_arg(sname.arg()), CowArg::Owned(npath.clone()));
 }
+
+#[itest]
+fn str_as_gstring_param() {
+    // Builtin method with a GString parameter.
+    let gstring = GString::from("GodotRocks");
+    assert!(gstring.begins_with("Godot"));
+    assert!(!gstring.begins_with("Rocks"));
+
+    let owned = String::from("Rocks");
+    assert!(gstring.ends_with(&owned));
+
+    // Engine method with a GString parameter.
+    let mut node = Node::new_alloc();
+    node.set_editor_description("literal description");
+    assert_eq!(
+        node.get_editor_description(),
+        GString::from("literal description")
+    );
+
+    node.set_editor_description(&owned);
+    assert_eq!(node.get_editor_description(), GString::from("Rocks"));
+
+    // Non-ASCII content is passed as UTF-8.
+    node.set_editor_description("Ünïcödé ✓");
+    assert_eq!(node.get_editor_description().to_string(), "Ünïcödé ✓");
+
+    // Empty string.
+    node.set_editor_description("");
+    assert!(node.get_editor_description().is_empty());
+
+    node.free();
+}
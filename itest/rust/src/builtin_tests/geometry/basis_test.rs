@@ -6,7 +6,7 @@
  */
 
 use godot::builtin::inner::InnerBasis;
-use godot::builtin::math::assert_eq_approx;
+use godot::builtin::math::{assert_eq_approx, assert_ne_approx, ApproxEq};
 use godot::builtin::{real, Basis, EulerOrder, RealConv, VariantOperator, Vector3};
 use godot::meta::ToGodot;
 
@@ -161,6 +161,41 @@ fn basis_equiv() {
     )
 }
 
+#[itest]
+fn basis_approx_eq_matches_godot() {
+    let inner = InnerBasis::from_outer(&TEST_BASIS);
+    let offset = |delta: real| {
+        let [a, b, c] = TEST_BASIS.rows;
+        let d = Vector3::splat(delta);
+        Basis::from_rows(a + d, b - d, c + d)
+    };
+
+    let nearly_equal = offset(1e-7);
+    let clearly_different = offset(1e-3);
+    let transposed = TEST_BASIS.transposed();
+
+    assert_eq_approx!(TEST_BASIS, nearly_equal);
+    assert_ne_approx!(TEST_BASIS, clearly_different);
+    assert_ne_approx!(TEST_BASIS, transposed);
+
+    // Same results as Godot's `Basis.is_equal_approx()`, which uses CMP_EPSILON relative to the magnitude of each component.
+    for other in [TEST_BASIS, nearly_equal, clearly_different, transposed] {
+        assert_eq!(
+            TEST_BASIS.approx_eq(&other),
+            inner.is_equal_approx(other),
+            "other: {other:?}"
+        );
+    }
+
+    // Determinant of a rotation is 1; scaling by (2, 3, 4) multiplies it by 24.
+    assert_eq_approx!(TEST_BASIS.determinant(), 1.0, fn = |a: &real, b: &real| (a - b).abs() < 1e-4);
+    assert_eq_approx!(
+        TEST_BASIS.scaled(Vector3::new(2.0, 3.0, 4.0)).determinant(),
+        24.0,
+        fn = |a: &real, b: &real| (a - b).abs() < 1e-3
+    );
+}
+
 fn deg_to_rad(rotation: Vector3) -> Vector3 {
     Vector3::new(
         rotation.x.to_radians(),
@@ -7,6 +7,7 @@
 
 use crate::framework::{expect_panic, itest};
 use godot::builtin::math::assert_eq_approx;
+use godot::builtin::real_consts::{FRAC_PI_2, FRAC_PI_4, PI};
 use godot::builtin::{Quaternion, Vector3};
 
 #[itest]
@@ -103,6 +104,57 @@ fn quaternion_slerpni() {
     assert_eq!(outcome, Quaternion::default());
 }
 
+#[itest]
+fn quaternion_slerp_known_rotations() {
+    let from = Quaternion::default();
+    let to = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+
+    assert_eq_approx!(from.slerp(to, 0.0), from);
+    assert_eq_approx!(from.slerp(to, 1.0), to);
+    assert_eq_approx!(
+        from.slerp(to, 0.5),
+        Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_4)
+    );
+
+    // Weights outside [0, 1] extrapolate.
+    assert_eq_approx!(
+        from.slerp(to, 2.0),
+        Quaternion::from_axis_angle(Vector3::UP, PI)
+    );
+    assert_eq_approx!(
+        from.slerp(to, -1.0),
+        Quaternion::from_axis_angle(Vector3::UP, -FRAC_PI_2)
+    );
+
+    // `-to` is the same rotation, but in the opposite hemisphere: slerp() takes the short way, slerpni() the long one.
+    let antipodal = -to;
+    assert_eq_approx!(
+        from.slerp(antipodal, 0.5),
+        Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_4)
+    );
+    assert_eq_approx!(from.angle_to(from.slerpni(antipodal, 0.5)), 3.0 * FRAC_PI_4);
+
+    expect_panic("slerp() with zero-length quaternion", || {
+        from.slerp(Quaternion::new(0.0, 0.0, 0.0, 0.0), 0.5);
+    });
+}
+
+#[itest]
+fn quaternion_angle_to() {
+    let from = Quaternion::default();
+    let to = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+
+    assert_eq_approx!(from.angle_to(from), 0.0);
+    assert_eq_approx!(from.angle_to(to), FRAC_PI_2);
+    assert_eq_approx!(to.angle_to(from), FRAC_PI_2);
+
+    // Sign of the quaternion doesn't matter.
+    assert_eq_approx!(from.angle_to(-to), FRAC_PI_2);
+
+    let half_turn = Quaternion::from_axis_angle(Vector3::RIGHT, PI);
+    assert_eq_approx!(from.angle_to(half_turn), PI);
+}
+
 #[itest]
 fn quaternion_spherical_cubic_interpolate() {
     let pre_a = Quaternion::new(-1.0, -1.0, -1.0, -1.0);
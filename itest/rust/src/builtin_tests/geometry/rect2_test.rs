@@ -20,6 +20,10 @@ fn rect2_inner_equivalence() {
         Rect2::from_components(4.2, 4.3, 1.5, 1.9),
         Rect2::from_components(8.2, 8.3, 2.5, 2.9),
         Rect2::from_components(8.2, 8.3, 2.5, 3.9),
+        // Touching edges and corners.
+        Rect2::from_components(0.0, 0.0, 2.0, 2.0),
+        Rect2::from_components(2.0, 0.0, 1.0, 2.0),
+        Rect2::from_components(2.0, 2.0, 1.0, 1.0),
     ];
     let vectors = [
         Vector2::ZERO,
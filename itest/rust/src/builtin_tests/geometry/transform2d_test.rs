@@ -45,18 +45,53 @@ fn transform2d_equiv() {
         outer.rotation(),
         "function: get_rotation\n"
     );
-    assert_eq_approx!(
-        real::from_f64(inner.get_rotation()),
-        outer.rotation(),
-        "function: get_rotation\n"
-    );
+    assert_eq_approx!(inner.get_scale(), outer.scale(), "function: get_scale\n");
     assert_eq_approx!(
         real::from_f64(inner.get_skew()),
         outer.skew(),
-        "function: get_scale\n"
+        "function: get_skew\n"
     );
 }
 
+#[itest]
+fn transform2d_decomposition_equiv() {
+    let transforms = [
+        // Rotation + scale + translation.
+        Transform2D::IDENTITY
+            .scaled(Vector2::new(2.0, 0.5))
+            .rotated(0.5)
+            .translated(Vector2::new(3.0, -1.0)),
+        // Flipped.
+        Transform2D::from_angle_scale_skew_origin(0.5, Vector2::new(-2.0, 3.0), 0.0, Vector2::ZERO),
+        Transform2D::from_angle_scale_skew_origin(-1.2, Vector2::new(1.5, -1.0), 0.3, Vector2::ONE),
+        // Degenerate.
+        Transform2D::from_cols(Vector2::new(2.0, 0.0), Vector2::ZERO, Vector2::ZERO),
+        Transform2D::from_cols(
+            Vector2::new(1.0, 2.0),
+            Vector2::new(2.0, 4.0),
+            Vector2::ZERO,
+        ),
+        Transform2D::from_cols(Vector2::ZERO, Vector2::ZERO, Vector2::ZERO),
+    ];
+
+    for outer in transforms {
+        let inner = InnerTransform2D::from_outer(&outer);
+
+        assert_eq_approx!(
+            real::from_f64(inner.get_rotation()),
+            outer.rotation(),
+            "get_rotation of {outer}"
+        );
+        assert_eq_approx!(inner.get_scale(), outer.scale(), "get_scale of {outer}");
+        assert_eq_approx!(
+            real::from_f64(inner.get_skew()),
+            outer.skew(),
+            "get_skew of {outer}"
+        );
+        assert_eq_approx!(inner.get_origin(), outer.origin, "get_origin of {outer}");
+    }
+}
+
 #[itest]
 fn transform2d_determinant() {
     let inner = InnerTransform2D::from_outer(&TEST_TRANSFORM);
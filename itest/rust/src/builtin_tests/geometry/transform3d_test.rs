@@ -5,9 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::framework::itest;
+use crate::framework::{itest, runs_release, suppress_godot_print};
 
 use godot::builtin::inner::InnerTransform3D;
+use godot::builtin::math::{assert_ne_approx, ApproxEq};
+use godot::builtin::real_consts::{FRAC_PI_2, FRAC_PI_4};
 use godot::builtin::{Aabb, Basis, Plane, Transform3D, VariantOperator, Vector3};
 use godot::meta::ToGodot;
 use godot::private::class_macros::assert_eq_approx;
@@ -44,6 +46,119 @@ fn transform3d_equiv() {
     }
 }
 
+#[itest]
+fn transform3d_approx_eq_matches_godot() {
+    let inner = InnerTransform3D::from_outer(&TEST_TRANSFORM);
+
+    // Tolerance is relative to each component's magnitude, so larger components allow larger absolute differences.
+    let nearly_equal = Transform3D::new(
+        TEST_TRANSFORM.basis,
+        TEST_TRANSFORM.origin + Vector3::new(1e-5, -1e-5, 1e-5),
+    );
+    let different_origin = TEST_TRANSFORM.translated(Vector3::new(0.0, 0.01, 0.0));
+    let different_basis = TEST_TRANSFORM.scaled_local(Vector3::new(1.0, 1.001, 1.0));
+
+    assert_eq_approx!(TEST_TRANSFORM, nearly_equal);
+    assert_ne_approx!(TEST_TRANSFORM, different_origin);
+    assert_ne_approx!(TEST_TRANSFORM, different_basis);
+
+    for other in [
+        TEST_TRANSFORM,
+        nearly_equal,
+        different_origin,
+        different_basis,
+        Transform3D::IDENTITY,
+    ] {
+        assert_eq!(
+            TEST_TRANSFORM.approx_eq(&other),
+            inner.is_equal_approx(other),
+            "other: {other:?}"
+        );
+    }
+}
+
+#[itest]
+fn transform3d_looking_at() {
+    let eye = Vector3::new(1.0, 2.0, 3.0);
+    let target = Vector3::new(4.0, 2.0, -1.0);
+    let camera = Transform3D::new(Basis::IDENTITY, eye).looking_at(target, Vector3::UP, false);
+
+    // -Z points at the target, +Y stays up for a horizontal view direction.
+    assert_eq_approx!(-camera.basis.col_c(), Vector3::new(0.6, 0.0, -0.8));
+    assert_eq_approx!(camera.basis.col_b(), Vector3::UP);
+    assert_eq_approx!(camera.basis.determinant(), 1.0);
+    assert_eq!(camera.origin, eye);
+    assert_eq_approx!(camera * Vector3::new(0.0, 0.0, -5.0), target);
+
+    // Model front: +Z points at the target.
+    let model = Transform3D::new(Basis::IDENTITY, eye).looking_at(target, Vector3::UP, true);
+    assert_eq_approx!(model.basis.col_c(), Vector3::new(0.6, 0.0, -0.8));
+    assert_eq_approx!(model.basis.col_b(), Vector3::UP);
+
+    // Up vector not perpendicular to view direction.
+    let tilted = Transform3D::new(Basis::IDENTITY, eye).looking_at(
+        eye + Vector3::new(0.0, 1.0, -1.0),
+        Vector3::UP,
+        false,
+    );
+    assert_eq_approx!(
+        -tilted.basis.col_c(),
+        Vector3::new(0.0, 1.0, -1.0).normalized()
+    );
+    assert_eq_approx!(tilted.basis.col_a(), Vector3::RIGHT);
+}
+
+#[itest]
+fn transform3d_looking_at_degenerate() {
+    // Godot only validates input in debug builds; release builds normalize zero vectors to zero.
+    let (expected_same_point, expected_parallel_up) = if runs_release() {
+        (
+            Basis::from_diagonal(0.0, 0.0, 0.0),
+            Basis::from_cols(Vector3::ZERO, Vector3::ZERO, Vector3::DOWN),
+        )
+    } else {
+        (Basis::IDENTITY, Basis::IDENTITY)
+    };
+
+    let eye = Vector3::new(1.0, 2.0, 3.0);
+    suppress_godot_print(|| {
+        let same_point = Transform3D::new(Basis::IDENTITY, eye).looking_at(eye, Vector3::UP, false);
+        assert_eq!(same_point, Transform3D::new(expected_same_point, eye));
+
+        let parallel_up = Basis::looking_at(Vector3::UP * 3.0, Vector3::UP, false);
+        assert_eq!(parallel_up, expected_parallel_up);
+        assert!(parallel_up.is_finite());
+    });
+}
+
+#[itest]
+fn transform3d_interpolate_with() {
+    let from = Transform3D::new(Basis::IDENTITY, Vector3::new(0.0, 2.0, 0.0));
+    let to = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_2).scaled(Vector3::splat(3.0)),
+        Vector3::new(4.0, 2.0, -8.0),
+    );
+
+    // Endpoints are exact.
+    assert_eq!(from.interpolate_with(&to, 0.0), from);
+    assert_eq!(from.interpolate_with(&to, 1.0), to);
+    assert_eq!(TEST_TRANSFORM.interpolate_with(&to, 1.0), to);
+
+    // Midpoint: half the rotation, average scale and origin.
+    let mid = from.interpolate_with(&to, 0.5);
+    let expected = Transform3D::new(
+        Basis::from_axis_angle(Vector3::UP, FRAC_PI_4).scaled(Vector3::splat(2.0)),
+        Vector3::new(2.0, 2.0, -4.0),
+    );
+    assert_eq_approx!(mid, expected);
+
+    // Nearly identical rotations must not produce NaN.
+    let nudged = Transform3D::new(Basis::from_axis_angle(Vector3::UP, 1e-6), Vector3::ZERO);
+    let result = Transform3D::IDENTITY.interpolate_with(&nudged, 0.5);
+    assert!(result.is_finite());
+    assert_eq_approx!(result, Transform3D::IDENTITY);
+}
+
 #[itest]
 fn transform3d_xform_equiv() {
     let vec = Vector3::new(1.0, 2.0, 3.0);
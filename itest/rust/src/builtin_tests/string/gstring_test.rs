@@ -8,7 +8,11 @@
 use std::collections::HashSet;
 
 use crate::framework::{expect_debug_panic_or_release_ok, itest};
-use godot::builtin::{Encoding, GString, PackedStringArray};
+use godot::builtin::math::assert_eq_approx;
+use godot::builtin::{
+    dict, varray, Encoding, GString, PackedStringArray, StringName, Variant, Vector2i,
+};
+use godot::meta::ToGodot;
 
 // TODO use tests from godot-rust/gdnative
 
@@ -90,6 +94,22 @@ fn string_chars() {
     assert_eq!(gstring, GString::from(string_chars.as_slice()));
 }
 
+#[itest]
+fn string_as_char_slice() {
+    let empty = GString::new();
+    assert!(empty.as_char_slice().is_empty());
+
+    let gstring = GString::from("ö🍎A💡");
+    let slice = gstring.as_char_slice();
+    assert_eq!(slice, &['ö', '🍎', 'A', '💡']);
+    assert_eq!(slice.len(), gstring.len()); // No trailing NUL.
+
+    // Borrowed directly from Godot's buffer: repeated calls and copy-on-write clones share the same storage.
+    let cloned = gstring.clone();
+    assert_eq!(slice.as_ptr(), gstring.as_char_slice().as_ptr());
+    assert_eq!(slice.as_ptr(), cloned.as_char_slice().as_ptr());
+}
+
 #[itest]
 fn string_unicode_at() {
     let s = GString::from("ö🍎A💡");
@@ -170,6 +190,164 @@ fn gstring_find() {
     assert_eq!(s.find_ex("O").r().n().from(6).done(), Some(4));
 }
 
+#[itest]
+fn gstring_case_conversion() {
+    // Expected values as printed by GDScript.
+    let s = GString::from("Grüezi Ñandú Αθήνα Москва 123");
+    assert_eq!(s.to_upper(), GString::from("GRÜEZI ÑANDÚ ΑΘΉΝΑ МОСКВА 123"));
+    assert_eq!(s.to_lower(), GString::from("grüezi ñandú αθήνα москва 123"));
+
+    assert_eq!(GString::new().to_upper(), GString::new());
+}
+
+#[itest]
+fn gstring_strip_edges_contains() {
+    let s = GString::from("\t  Hello World \n");
+    assert_eq!(s.strip_edges(true, true), GString::from("Hello World"));
+    assert_eq!(s.strip_edges(false, true), GString::from("\t  Hello World"));
+    assert_eq!(s.strip_edges(true, false), GString::from("Hello World \n"));
+
+    // Only ASCII whitespace/control characters are stripped, unlike Rust's str::trim().
+    let nbsp = GString::from("\u{a0}x\u{a0}");
+    assert_eq!(nbsp.strip_edges(true, true), nbsp);
+
+    let s = GString::from("Hello Wörld");
+    assert!(s.contains("Wö"));
+    assert!(s.contains("Hello Wörld"));
+    assert!(!s.contains("wö"));
+    assert!(!s.contains("Hello Wörld!"));
+}
+
+#[itest]
+fn gstring_similarity() {
+    let similarity = |a: &str, b: &str| GString::from(a).similarity(b);
+
+    // Values from Godot's `String.similarity()` docs.
+    assert_eq!(similarity("ABC123", "ABC123"), 1.0);
+    assert_eq!(similarity("ABC123", "XYZ456"), 0.0);
+    assert_eq_approx!(similarity("ABC123", "123ABC"), 0.8);
+    assert_eq_approx!(similarity("ABC123", "abc123"), 0.4);
+
+    // Empty and single-character strings.
+    assert_eq!(similarity("", ""), 1.0);
+    assert_eq!(similarity("a", "a"), 1.0);
+    assert_eq!(similarity("a", "b"), 0.0);
+    assert_eq!(similarity("", "ab"), 0.0);
+
+    // Also available on StringName.
+    assert_eq_approx!(StringName::from("ABC123").similarity("123ABC"), 0.8);
+}
+
+#[itest]
+fn gstring_match_glob() {
+    let s = GString::from("icon_player.png");
+
+    assert!(s.match_glob("*.png"));
+    assert!(s.match_glob("icon_*"));
+    assert!(s.match_glob("icon_??????.png"));
+    assert!(!s.match_glob("*.svg"));
+    assert!(!s.match_glob("ICON_*"));
+
+    // matchn is case-insensitive.
+    assert!(s.matchn_glob("ICON_*.PNG"));
+    assert!(!s.matchn_glob("*.svg"));
+
+    // Empty strings or patterns never match.
+    assert!(!GString::new().match_glob("*"));
+    assert!(!s.match_glob(""));
+}
+
+#[itest]
+fn gstring_path_helpers() {
+    let path = GString::from("res://dir/file.tscn");
+
+    // Expected values are GDScript's results.
+    assert_eq!(path.get_extension(), "tscn".into());
+    assert_eq!(path.get_basename(), "res://dir/file".into());
+    assert_eq!(path.get_file(), "file.tscn".into());
+    assert_eq!(path.get_base_dir(), "res://dir".into());
+    assert_eq!(
+        GString::from("res://file.tscn").get_base_dir(),
+        "res://".into()
+    );
+
+    // A separator is inserted only if neither side has one.
+    let dir = GString::from("res://dir");
+    assert_eq!(dir.path_join("file.tscn"), path);
+    assert_eq!(GString::from("res://dir/").path_join("file.tscn"), path);
+    assert_eq!(dir.path_join("/file.tscn"), path);
+    assert_eq!(GString::new().path_join("file.tscn"), "file.tscn".into());
+
+    // Trailing slashes: no file name.
+    let dir_slash = GString::from("res://dir/");
+    assert_eq!(dir_slash.get_file(), GString::new());
+    assert_eq!(dir_slash.get_base_dir(), "res://dir".into());
+
+    // Extensionless names, and dots in directory names.
+    let readme = GString::from("res://dir/README");
+    assert_eq!(readme.get_extension(), GString::new());
+    assert_eq!(readme.get_basename(), readme);
+    let dotted_dir = GString::from("res://dir.d/file");
+    assert_eq!(dotted_dir.get_extension(), GString::new());
+    assert_eq!(dotted_dir.get_basename(), dotted_dir);
+
+    // Only the last extension counts; unlike std::path, a leading dot starts an extension.
+    let archive = GString::from("user://archive.tar.gz");
+    assert_eq!(archive.get_extension(), "gz".into());
+    assert_eq!(archive.get_basename(), "user://archive.tar".into());
+    let hidden = GString::from(".gitignore");
+    assert_eq!(hidden.get_extension(), "gitignore".into());
+    assert_eq!(hidden.get_basename(), GString::new());
+}
+
+#[itest]
+fn gstring_to_int_lenient() {
+    // Unlike str::parse(), non-digit characters are skipped, and parsing stops at the first '.'.
+    assert_eq!(GString::from("123").to_int(), 123);
+    assert_eq!(GString::from("3abc").to_int(), 3);
+    assert_eq!(GString::from("-12.34").to_int(), -12);
+    assert_eq!(GString::from("1e3").to_int(), 13);
+    assert_eq!(GString::from("1_2_3").to_int(), 123);
+    assert_eq!(GString::from("+7").to_int(), 7);
+    assert_eq!(GString::from("").to_int(), 0);
+    assert_eq!(GString::from("abc").to_int(), 0);
+
+    assert!(GString::from("7").is_valid_int());
+    assert!(GString::from("-12").is_valid_int());
+    assert!(GString::from("+3").is_valid_int());
+    assert!(!GString::from("1.65").is_valid_int());
+    assert!(!GString::from("3abc").is_valid_int());
+    assert!(!GString::from("").is_valid_int());
+}
+
+#[itest]
+fn gstring_to_float_lenient() {
+    // Parsing stops at the first character that cannot continue the number.
+    assert_eq_approx!(GString::from("12.35").to_float(), 12.35);
+    assert_eq!(GString::from("-2.5").to_float(), -2.5);
+    assert_eq_approx!(GString::from("1.2.3").to_float(), 1.2);
+    assert_eq!(GString::from("12xy3").to_float(), 12.0);
+    assert_eq!(GString::from("1e3").to_float(), 1000.0);
+    assert_eq!(GString::from("Hello!").to_float(), 0.0);
+    assert_eq!(GString::from("").to_float(), 0.0);
+
+    assert!(GString::from("1.7").is_valid_float());
+    assert!(GString::from("24").is_valid_float());
+    assert!(GString::from("7e3").is_valid_float());
+    assert!(GString::from("-0.5").is_valid_float());
+    assert!(!GString::from("12xy3").is_valid_float());
+    assert!(!GString::from("").is_valid_float());
+}
+
+#[itest]
+fn gstring_split_multichar() {
+    let s = GString::from("a--b--c");
+    assert_eq!(s.split("--"), packed(&["a", "b", "c"]));
+    assert_eq!(GString::from("a---b").split("--"), packed(&["a", "-b"]));
+    assert_eq!(GString::from("ä→ö→ü").split("→"), packed(&["ä", "ö", "ü"]));
+    assert_eq!(GString::new().split(","), packed(&[""]));
+}
+
 #[itest]
 fn gstring_split() {
     let s = GString::from("Hello World");
@@ -266,6 +444,54 @@ fn gstring_pad() {
     assert_eq!(s.pad_zeros(2), "123.456".into());
 }
 
+#[itest]
+fn gstring_format() {
+    let text = GString::from("{0} has {1} items; {2} stays");
+
+    let formatted = text.format(&varray!["Bag", 3].to_variant());
+    assert_eq!(formatted, "Bag has 3 items; {2} stays".into());
+
+    let formatted = text.format(&varray!["Bag", 3, 4.5, "extra"].to_variant());
+    assert_eq!(formatted, "Bag has 3 items; 4.5 stays".into());
+
+    let formatted = GString::from("{name} is {age} years old")
+        .format(&dict! { "name": "Godette", "age": 9 }.to_variant());
+    assert_eq!(formatted, "Godette is 9 years old".into());
+
+    let formatted = GString::from("$x + $y")
+        .format_with_placeholder(&dict! { "x": 1, "y": 2 }.to_variant(), "$_");
+    assert_eq!(formatted, "1 + 2".into());
+}
+
+#[itest]
+fn gstring_sprintf() {
+    let sprintf = |format: &str, values: Variant| GString::from(format).sprintf(&values);
+
+    assert_eq!(sprintf("%d items", 5.to_variant()), Some("5 items".into()));
+    assert_eq!(
+        sprintf("%s: %05.2f", varray!["Pi", 3.14159].to_variant()),
+        Some("Pi: 03.14".into())
+    );
+    assert_eq!(
+        sprintf("%x|%-4s|%%", varray![255, "ab"].to_variant()),
+        Some("ff|ab  |%".into())
+    );
+
+    // %s accepts any value.
+    assert_eq!(
+        sprintf(
+            "%s and %s",
+            varray![Vector2i::new(1, 2), Variant::nil()].to_variant()
+        ),
+        Some("(1, 2) and <null>".into())
+    );
+
+    // Mismatched number or types of values.
+    assert_eq!(sprintf("%s %s", varray!["one"].to_variant()), None);
+    assert_eq!(sprintf("%s", varray!["one", "two"].to_variant()), None);
+    assert_eq!(sprintf("%d", "text".to_variant()), None);
+}
+
 // Byte and C-string conversions.
 crate::generate_string_bytes_and_cstr_tests!(
     builtin: GString,
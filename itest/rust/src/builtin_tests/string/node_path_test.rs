@@ -126,3 +126,30 @@ fn node_path_get_subname() {
         assert_eq!(path.get_subname(2), "".into());
     })
 }
+
+#[itest]
+fn node_path_try_get_name_subname() {
+    let path = NodePath::from("../Player:position:x");
+    assert!(!path.is_absolute());
+    assert_eq!(path.get_name_count(), 2);
+    assert_eq!(path.get_subname_count(), 2);
+
+    assert_eq!(path.try_get_name(0), Some("..".into()));
+    assert_eq!(path.try_get_name(1), Some("Player".into()));
+    assert_eq!(path.try_get_name(2), None);
+
+    assert_eq!(path.try_get_subname(0), Some("position".into()));
+    assert_eq!(path.try_get_subname(1), Some("x".into()));
+    assert_eq!(path.try_get_subname(2), None);
+
+    let absolute = NodePath::from("/root/Main");
+    assert!(absolute.is_absolute());
+    assert_eq!(absolute.try_get_name(1), Some("Main".into()));
+
+    let empty = NodePath::default();
+    assert!(!empty.is_absolute());
+    assert_eq!(empty.get_name_count(), 0);
+    assert_eq!(empty.get_subname_count(), 0);
+    assert_eq!(empty.try_get_name(0), None);
+    assert_eq!(empty.try_get_subname(0), None);
+}
@@ -34,6 +34,28 @@ fn string_name_conversion() {
     assert_eq!(string, back);
 }
 
+#[itest]
+fn string_name_intern() {
+    let interned = StringName::intern("interned_name");
+    assert_eq!(interned, StringName::from("interned_name"));
+    assert_eq!(StringName::intern("interned_name"), interned);
+
+    // Unicode beyond Latin-1.
+    assert_eq!(StringName::intern("名前🍎"), StringName::from("名前🍎"));
+    assert_ne!(StringName::intern("other_name"), interned);
+}
+
+#[itest]
+#[cfg(feature = "experimental-threads")]
+fn string_name_intern_other_thread() {
+    // Off the main thread, the cache is bypassed; the result must still be equal.
+    let name = std::thread::spawn(|| StringName::intern("interned_name"))
+        .join()
+        .unwrap();
+
+    assert_eq!(name, StringName::intern("interned_name"));
+}
+
 #[itest]
 fn string_name_node_path_conversion() {
     let string = StringName::from("some string");
@@ -117,6 +139,33 @@ fn string_name_length() {
     assert_eq!(empty.len(), 0);
 }
 
+#[itest]
+fn string_name_to_string() {
+    let names = [
+        String::new(),
+        "hello!".to_string(),
+        "名前🍎 with ünïcödé".to_string(),
+        // Longer than the internal formatting buffer, with multi-byte characters across chunk boundaries.
+        "ab🍎".repeat(100),
+    ];
+
+    for string in names {
+        let name = StringName::from(&string);
+
+        assert_eq!(name.to_string(), string);
+        assert_eq!(String::from(&name), string);
+        assert_eq!(format!("{name:?}"), format!("&\"{string}\""));
+    }
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn string_name_to_string_static() {
+    let name = StringName::from(c"static name");
+    assert_eq!(name.to_string(), "static name");
+    assert_eq!(String::from(&name), "static name");
+}
+
 #[itest]
 fn string_name_is_empty() {
     let name = StringName::from("hello!");
@@ -7,7 +7,9 @@
 
 use crate::framework::itest;
 
-use godot::global::{Key, KeyModifierMask};
+use godot::builtin::Variant;
+use godot::global::{Key, KeyModifierMask, PropertyUsageFlags};
+use godot::meta::ToGodot;
 use godot::obj::{EngineBitfield, EngineEnum};
 
 #[itest]
@@ -28,3 +30,50 @@ fn enum_with_masked_bitfield() {
     key |= KeyModifierMask::SHIFT;
     assert_eq!(key.ord(), 65 | (1 << 25));
 }
+
+#[itest]
+fn bitfield_operations() {
+    let storage = PropertyUsageFlags::STORAGE;
+    let editor = PropertyUsageFlags::EDITOR;
+    let internal = PropertyUsageFlags::INTERNAL;
+
+    let flags = storage | editor;
+    assert_eq!(flags, PropertyUsageFlags::DEFAULT);
+
+    assert!(flags.contains(storage));
+    assert!(flags.contains(storage | editor));
+    assert!(!flags.contains(storage | internal));
+    assert!(flags.is_set(storage | internal));
+    assert!(!flags.is_set(internal));
+
+    assert_eq!(flags & editor, editor);
+    assert_eq!(flags & internal, PropertyUsageFlags::NONE);
+
+    let mut flags = flags;
+    flags |= internal;
+    assert!(flags.contains(internal));
+    flags &= internal | editor;
+    assert_eq!(flags, internal | editor);
+}
+
+#[itest]
+fn bitfield_from_variant_keeps_unknown_bits() {
+    let unknown_bit = 1 << 40;
+    let variant = Variant::from(PropertyUsageFlags::DEFAULT.ord() as i64 | unknown_bit);
+
+    let flags = variant.to::<PropertyUsageFlags>();
+    assert_eq!(
+        flags.ord(),
+        PropertyUsageFlags::DEFAULT.ord() | unknown_bit as u64
+    );
+    assert!(flags.contains(PropertyUsageFlags::DEFAULT));
+    assert_eq!(
+        flags & PropertyUsageFlags::EDITOR,
+        PropertyUsageFlags::EDITOR
+    );
+
+    // Unknown bits survive operations and the way back.
+    let combined = flags | PropertyUsageFlags::INTERNAL;
+    assert!(combined.contains(PropertyUsageFlags::from_ord(unknown_bit as u64)));
+    assert_eq!(flags.to_variant(), variant);
+}
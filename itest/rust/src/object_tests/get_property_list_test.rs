@@ -78,3 +78,18 @@ fn get_property_list_returns() {
 
     obj.free();
 }
+
+#[itest]
+fn optional_builtin_property_info() {
+    let inner = PropertyInfo::new_var::<GString>("name");
+    let info = PropertyInfo::new_var::<Option<GString>>("name");
+
+    // Passed as Variant, so that null is accepted, but with the hint of the inner type.
+    assert_eq!(info.variant_type, VariantType::NIL);
+    assert_eq!(
+        info.usage,
+        PropertyUsageFlags::DEFAULT | PropertyUsageFlags::NIL_IS_VARIANT
+    );
+    assert_eq!(info.hint_info.hint, inner.hint_info.hint);
+    assert_eq!(info.hint_info.hint_string, inner.hint_info.hint_string);
+}